@@ -0,0 +1,114 @@
+use lunar::{
+    app::App,
+    entities::{
+        animated_object::{AnimatedObject, AnimationClip, AnimationEvent, PlaybackMode},
+        char_object::{CharMap, CharObject},
+    },
+    game::Game,
+    input::SpecialKey,
+    types::vector2int::Vector2Int,
+};
+use ndarray::{array, Array2};
+
+/// Frames have to outlive the animation since frames point to them
+struct Frames {
+    idle: [Array2<char>; 2],
+    walk: [Array2<char>; 2],
+    explode: [Array2<char>; 3],
+}
+
+struct Robot {
+    animation: AnimatedObject,
+    status: String,
+}
+
+impl Robot {
+    fn new(frames: &Frames) -> Self {
+        let idle = AnimationClip::new(PlaybackMode::PingPong)
+            .add_frame(CharMap::Chars(&frames.idle[0]), 0.6)
+            .add_frame(CharMap::Chars(&frames.idle[1]), 0.2);
+        let walk = AnimationClip::new(PlaybackMode::Loop)
+            .add_frame(CharMap::Chars(&frames.walk[0]), 0.15)
+            .add_frame(CharMap::Chars(&frames.walk[1]), 0.15);
+        let explode = AnimationClip::new(PlaybackMode::Once)
+            .add_frame(CharMap::Chars(&frames.explode[0]), 0.1)
+            .add_frame(CharMap::Chars(&frames.explode[1]), 0.2)
+            .add_frame(CharMap::Chars(&frames.explode[2]), 0.4);
+
+        let mut animation = AnimatedObject::new(CharObject::new(&frames.idle[0]))
+            .add_clip("idle", idle)
+            .add_clip("walk", walk)
+            .add_clip("explode", explode);
+        animation
+            .get_sprite_mut()
+            .move_to(Vector2Int { x: 48, y: 13 });
+        animation.play("idle");
+
+        Self {
+            animation,
+            status: String::from("idle"),
+        }
+    }
+}
+
+impl Game for Robot {
+    fn update(&mut self, app: &mut App) {
+        if app.input.is_char_key_down('q') {
+            app.quit();
+        }
+
+        // Walk while arrow keys are down, explode with E
+        if self.animation.get_clip_name() != Some("explode") {
+            let left = app.input.is_special_key_down(SpecialKey::Left);
+            let right = app.input.is_special_key_down(SpecialKey::Right);
+            if left || right {
+                let direction = if left { -1 } else { 1 };
+                self.animation
+                    .get_sprite_mut()
+                    .move_by(Vector2Int { x: direction, y: 0 });
+                self.animation.play("walk");
+            } else {
+                self.animation.play("idle");
+            }
+            if app.input.is_char_key_down('e') {
+                self.animation.play("explode");
+            }
+        }
+
+        self.animation.update(app.time.get_delta());
+        for event in self.animation.take_events() {
+            if let AnimationEvent::Finished(clip) = event {
+                self.status = format!("{} finished", clip);
+                self.animation.play("idle");
+            }
+        }
+    }
+
+    fn render(&mut self, app: &mut App) {
+        self.animation.render(app);
+        app.draw_str(
+            Vector2Int { x: 2, y: 1 },
+            &format!("arrows walk, E explodes - {}", self.status),
+        );
+    }
+}
+
+fn main() {
+    let frames = Frames {
+        idle: [
+            array![[' ', 'o', ' '], ['/', '#', '\\'], [' ', 'A', ' ']],
+            array![[' ', '-', ' '], ['/', '#', '\\'], [' ', 'A', ' ']],
+        ],
+        walk: [
+            array![[' ', 'o', ' '], ['/', '#', '\\'], ['/', ' ', '\\']],
+            array![[' ', 'o', ' '], ['/', '#', '\\'], [' ', '|', ' ']],
+        ],
+        explode: [
+            array![[' ', '*', ' '], ['*', '#', '*'], [' ', '*', ' ']],
+            array![['\\', '|', '/'], ['-', '*', '-'], ['/', '|', '\\']],
+            array![['.', ' ', '.'], [' ', ' ', ' '], ['.', ' ', '.']],
+        ],
+    };
+
+    App::new().run_game(&mut Robot::new(&frames));
+}
@@ -0,0 +1,96 @@
+use lunar::{
+    app::App,
+    entities::{char_object::CharObject, text_object::TextObject},
+    input::SpecialKey,
+    rendering::viewport::Viewport,
+    types::{rect_int::RectInt, vector2int::Vector2Int},
+};
+use ndarray::{array, Array2};
+use std::cell::Cell;
+
+const WORLD_WIDTH: usize = 300;
+const WORLD_HEIGHT: usize = 90;
+const HUD_LAYER: i32 = 10;
+
+fn main() {
+    // Create a lunar app
+    let app = App::new();
+
+    // A world several screens large, with a tree every few cells
+    let world_map = Array2::from_shape_fn((WORLD_HEIGHT, WORLD_WIDTH), |(y, x)| {
+        if (x * 7 + y * 13) % 23 == 0 {
+            '^'
+        } else {
+            '.'
+        }
+    });
+    let world = CharObject::new(&world_map);
+
+    let player_map = array![['@']];
+    let player = Cell::new(CharObject::new(&player_map).set_layer(1));
+
+    let hud_string = String::from("Arrow keys to move, Q to quit");
+    let hud = TextObject::new(&hud_string)
+        .set_layer(HUD_LAYER)
+        .move_to(Vector2Int { x: 2, y: 0 });
+
+    // Initialize App
+    let init = |app: &mut App| {
+        app.set_title("Camera".to_string());
+        app.set_layer_screen_space(HUD_LAYER, true);
+
+        // One viewport inside the border, following the player
+        let mut viewport = Viewport::new(RectInt::new(
+            Vector2Int::identity(),
+            app.get_size() - Vector2Int { x: 2, y: 2 },
+        ));
+        viewport.camera.set_dead_zone(Vector2Int { x: 20, y: 8 });
+        viewport.camera.set_bounds(RectInt::new(
+            Vector2Int::zero(),
+            Vector2Int {
+                x: WORLD_WIDTH as i32,
+                y: WORLD_HEIGHT as i32,
+            },
+        ));
+        app.add_viewport(viewport);
+
+        player.set(player.get().move_to(Vector2Int { x: 10, y: 10 }));
+    };
+
+    // Update App
+    let update = |app: &mut App| {
+        if app.input.is_char_key_down('q') {
+            app.quit();
+        }
+
+        let directions = [
+            (SpecialKey::Up, Vector2Int::down()),
+            (SpecialKey::Down, Vector2Int::up()),
+            (SpecialKey::Left, Vector2Int::left()),
+            (SpecialKey::Right, Vector2Int::right()),
+        ];
+        for (key, direction) in directions.iter() {
+            if app.input.is_special_key_down(*key) {
+                player.set(player.get().move_by(*direction));
+            }
+        }
+
+        let target = player.get().get_position();
+        if let Some(viewport) = app.get_viewport_mut(0) {
+            viewport.camera.set_target(target);
+        }
+    };
+
+    // Render objects on App window
+    let render = |app: &mut App| {
+        world.render(app);
+        player.get().render(app);
+        hud.render(app);
+    };
+
+    // On App exit
+    let exit = |_app: &mut App| {};
+
+    // Run App
+    app.run(init, update, render, exit);
+}
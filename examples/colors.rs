@@ -0,0 +1,57 @@
+use lunar::{
+    app::App,
+    backend::ansi_backend::AnsiBackend,
+    rendering::cell::Cell,
+    types::{color32::Color32, vector2int::Vector2Int},
+};
+use std::env;
+
+fn main() {
+    // Run with `--ansi` to render through escape codes, which supports 24-bit color
+    let app = if env::args().any(|arg| arg == "--ansi") {
+        App::with_backend(Box::new(AnsiBackend::terminal(Vector2Int {
+            x: 100,
+            y: 30,
+        })))
+    } else {
+        App::new()
+    };
+
+    // Initialize App
+    let init = |app: &mut App| {
+        app.set_title("Colors".to_string());
+        app.set_background_color(Color32::from_hex("1c1c1c"));
+    };
+
+    // Update App
+    let update = |app: &mut App| {
+        if app.input.is_char_key_down('q') {
+            app.quit();
+        }
+    };
+
+    // Render a hue gradient, reduced to whatever color depth the backend supports
+    let render = |app: &mut App| {
+        for y in 1..app.get_height() - 1 {
+            for x in 1..app.get_width() - 1 {
+                let r = (x * 255 / app.get_width()) as u32;
+                let g = (y * 255 / app.get_height()) as u32;
+                let cell = Cell::blank().with_background(Color32 {
+                    r,
+                    g,
+                    b: 255 - r,
+                    a: 255,
+                });
+                app.draw_cell(Vector2Int { x, y }, cell);
+            }
+        }
+        let depth = format!("{:?}", app.get_color_depth());
+        app.draw_str(Vector2Int { x: 2, y: 1 }, &depth);
+    };
+
+    // On App exit
+    let exit = |_app: &mut App| {};
+
+    // Run App
+    app.run(init, update, render, exit);
+}
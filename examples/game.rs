@@ -0,0 +1,82 @@
+use lunar::{
+    app::App,
+    entities::char_object::CharObject,
+    game::Game,
+    input::{InputEvent, SpecialKey},
+    types::vector2int::Vector2Int,
+};
+use ndarray::{array, Array2};
+
+/// Game state lives in a plain struct instead of Cells shared by closures
+struct Snake {
+    head_map: Array2<char>,
+    position: Vector2Int,
+    direction: Vector2Int,
+    key_count: u32,
+}
+
+impl Game for Snake {
+    fn init(&mut self, app: &mut App) {
+        app.set_title("Game trait".to_string());
+        app.set_size(Vector2Int { x: 60, y: 20 });
+
+        // Move 10 cells per second whatever the frame rate is
+        app.time.set_fixed_delta(0.1);
+    }
+
+    fn on_event(&mut self, app: &mut App, event: InputEvent) {
+        self.key_count += 1;
+        match event {
+            InputEvent::Character('q') => app.quit(),
+            InputEvent::Character('+') => app.set_size(app.get_size() + Vector2Int { x: 4, y: 2 }),
+            InputEvent::Special(SpecialKey::Up) => self.direction = Vector2Int { x: 0, y: -1 },
+            InputEvent::Special(SpecialKey::Down) => self.direction = Vector2Int { x: 0, y: 1 },
+            InputEvent::Special(SpecialKey::Left) => self.direction = Vector2Int::left(),
+            InputEvent::Special(SpecialKey::Right) => self.direction = Vector2Int::right(),
+            _ => {}
+        }
+    }
+
+    fn fixed_update(&mut self, app: &mut App) {
+        // Wrap around the inside of the borders
+        let size = app.get_size();
+        self.position += self.direction;
+        self.position.x = (self.position.x - 1).rem_euclid(size.x - 2) + 1;
+        self.position.y = (self.position.y - 1).rem_euclid(size.y - 2) + 1;
+    }
+
+    fn on_resize(&mut self, _app: &mut App, size: Vector2Int) {
+        // Restart from the middle of the new screen
+        self.position = Vector2Int {
+            x: size.x / 2,
+            y: size.y / 2,
+        };
+    }
+
+    fn render(&mut self, app: &mut App) {
+        CharObject::new(&self.head_map)
+            .move_to(self.position)
+            .render(app);
+        app.draw_str(
+            Vector2Int { x: 2, y: 1 },
+            &format!(
+                "events: {}, fps: {:.0}, + grows the screen",
+                self.key_count,
+                app.time.get_fps()
+            ),
+        );
+    }
+}
+
+fn main() {
+    let mut game = Snake {
+        head_map: array![['O']],
+        position: Vector2Int { x: 30, y: 10 },
+        direction: Vector2Int::right(),
+        key_count: 0,
+    };
+
+    // Run a Game, its state is still available once the App is done
+    App::new().run_game(&mut game);
+    println!("{} input events", game.key_count);
+}
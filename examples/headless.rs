@@ -0,0 +1,61 @@
+use lunar::{
+    app::App,
+    backend::headless_backend::HeadlessBackend,
+    entities::char_object::CharObject,
+    input::{InputEvent, SpecialKey},
+    types::vector2int::Vector2Int,
+};
+use ndarray::array;
+use std::cell::Cell;
+
+fn main() {
+    // Create a headless backend and keep a handle to it for feeding input and inspecting frames
+    let backend = HeadlessBackend::new(Vector2Int { x: 20, y: 8 });
+    let screen = backend.clone();
+    let app = App::with_backend(Box::new(backend.clone()));
+
+    let paddle_map = array![['['], ['['], ['[']];
+    let paddle = Cell::new(CharObject::new(&paddle_map));
+
+    // Press the down key twice, both events are read on the first frame
+    screen.push_input(InputEvent::Special(SpecialKey::Down));
+    screen.push_input(InputEvent::Special(SpecialKey::Down));
+
+    // Initialize App
+    let init = |app: &mut App| {
+        app.set_size(Vector2Int { x: 20, y: 8 });
+        paddle.set(paddle.get().move_to(Vector2Int { x: 2, y: 1 }));
+    };
+
+    // Update App
+    let update = |app: &mut App| {
+        // Quit once a few frames have been presented
+        if screen.get_frame_count() >= 3 {
+            app.quit();
+        }
+        // Move the paddle down once per key event
+        for event in app.input.get_events() {
+            if *event == InputEvent::Special(SpecialKey::Down) {
+                paddle.set(paddle.get().move_by(Vector2Int::up()));
+            }
+        }
+    };
+
+    // Render objects on App window
+    let render = |app: &mut App| {
+        paddle.get().render(app);
+    };
+
+    // On App exit
+    let exit = |_app: &mut App| {};
+
+    // Run App
+    app.run(init, update, render, exit);
+
+    // Inspect the last frame
+    println!("{}", screen.get_text());
+    println!("frames: {}", screen.get_frame_count());
+    println!("cells written last frame: {}", screen.get_cells_written());
+    assert_eq!(screen.get_char(Vector2Int { x: 2, y: 3 }), Some('['));
+    assert_eq!(screen.get_char(Vector2Int { x: 2, y: 5 }), Some('['));
+}
@@ -0,0 +1,94 @@
+use lunar::{
+    app::App,
+    assets::char_map_loader::CharMapAsset,
+    ecs::entity::Entity,
+    entities::{char_object::CharObject, game_object::GameObject},
+    input::SpecialKey,
+    types::vector2int::Vector2Int,
+};
+use ndarray::array;
+use std::cell::Cell;
+
+fn main() {
+    // Create a lunar app
+    let app = App::new();
+
+    // Entities are known once the app is initialized
+    let ship = Cell::new(None::<Entity>);
+    let right_turret = Cell::new(None::<Entity>);
+
+    // Initialize App with a ship and two turrets, turret positions are relative to the ship
+    let init = |app: &mut App| {
+        app.set_title("Hierarchy".to_string());
+
+        // Sprites hold handles to char maps kept by the App
+        let ship_map = CharMapAsset::new(array![[' ', '^', ' '], ['<', '#', '>']], None, None);
+        let ship_map = app.assets.add(ship_map.unwrap());
+        let turret_map = CharMapAsset::new(array![['|']], None, None);
+        let turret_map = app.assets.add(turret_map.unwrap());
+
+        let (assets, world) = (&app.assets, &mut app.world);
+        let ship_entity = world.spawn();
+        world.add_component(
+            ship_entity,
+            GameObject::new().move_to(Vector2Int { x: 48, y: 14 }),
+        );
+        world.add_component(
+            ship_entity,
+            CharObject::from_asset(assets, ship_map).unwrap(),
+        );
+
+        for x in [0, 2].iter() {
+            let turret = world.spawn();
+            world.add_component(
+                turret,
+                GameObject::new()
+                    .set_parent(ship_entity)
+                    .move_to(Vector2Int { x: *x, y: -1 }),
+            );
+            world.add_component(turret, CharObject::from_asset(assets, turret_map).unwrap());
+            right_turret.set(Some(turret));
+        }
+        ship.set(Some(ship_entity));
+    };
+
+    // Update App, moving the ship moves its turrets along
+    let update = |app: &mut App| {
+        if app.input.is_char_key_down('q') {
+            app.quit();
+        }
+        let (ship, right_turret) = (ship.get().unwrap(), right_turret.get().unwrap());
+        let input = &app.input;
+        let world = &mut app.world;
+        if input.is_special_key_down(SpecialKey::Left) {
+            world
+                .get_component_mut::<GameObject>(ship)
+                .unwrap()
+                .move_by(Vector2Int::left());
+        }
+        if input.is_special_key_down(SpecialKey::Right) {
+            world
+                .get_component_mut::<GameObject>(ship)
+                .unwrap()
+                .move_by(Vector2Int::right());
+        }
+
+        // Space hides the ship with its turrets, T only the right turret
+        for (key, entity) in [(' ', ship), ('t', right_turret)].iter() {
+            if input.is_pressed(*key) {
+                let game_object = world.get_component_mut::<GameObject>(*entity).unwrap();
+                let visible = game_object.get_visible();
+                game_object.set_visible(!visible);
+            }
+        }
+    };
+
+    // Entities of the world are rendered by the app
+    let render = |_app: &mut App| {};
+
+    // On App exit
+    let exit = |_app: &mut App| {};
+
+    // Run App
+    app.run(init, update, render, exit);
+}
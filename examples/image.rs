@@ -0,0 +1,64 @@
+use lunar::{
+    app::App,
+    assets::{
+        char_map_loader::{CharMapAsset, CharMapLoader},
+        image_converter::ImageConverter,
+    },
+    entities::game_object::GameObject,
+    types::vector2int::Vector2Int,
+};
+use std::env;
+
+fn main() {
+    // Show an image given as argument, or the moon
+    let path = env::args().nth(1).unwrap_or(format!(
+        "{}/examples/assets/moon.ppm",
+        env!("CARGO_MANIFEST_DIR")
+    ));
+
+    // Create a lunar app
+    let app = App::new();
+
+    // Initialize App, convert the image to 64 cells wide colored art
+    let init = move |app: &mut App| {
+        app.set_title("Image".to_string());
+        let converter = ImageConverter::new().set_width(64);
+        app.assets
+            .set_loader(CharMapLoader::new().set_image_converter(converter));
+
+        let art = match app.assets.load_char_map(&path) {
+            Some(art) => art,
+            None => {
+                app.quit();
+                return;
+            }
+        };
+        let art_map = app.assets.get::<CharMapAsset>(art).unwrap();
+        let position = Vector2Int {
+            x: (app.get_width() - art_map.get_width() as i32) / 2,
+            y: (app.get_height() - art_map.get_height() as i32) / 2,
+        };
+        let sprite = art_map.to_char_object();
+
+        let entity = app.world.spawn();
+        app.world
+            .add_component(entity, GameObject::new().move_to(position));
+        app.world.add_component(entity, sprite);
+    };
+
+    // Update App
+    let update = |app: &mut App| {
+        if app.input.is_char_key_down('q') {
+            app.quit();
+        }
+    };
+
+    // Entities are rendered by the App itself
+    let render = |_app: &mut App| {};
+
+    // On App exit
+    let exit = |_app: &mut App| {};
+
+    // Run App
+    app.run(init, update, render, exit);
+}
@@ -0,0 +1,100 @@
+use lunar::{
+    app::App,
+    game::Game,
+    input::{InputEvent, Key, Modifiers, SpecialKey},
+    types::vector2int::Vector2Int,
+};
+
+const LOG_LENGTH: usize = 16;
+
+/// Key tester listing the last input events and the keys held down
+struct KeyTester {
+    log: Vec<String>,
+}
+
+impl Game for KeyTester {
+    fn init(&mut self, app: &mut App) {
+        app.set_title("Keys".to_string());
+        app.set_size(Vector2Int { x: 60, y: 22 });
+        app.set_all_borders('.');
+        app.set_all_corners('+');
+    }
+
+    fn on_event(&mut self, _app: &mut App, event: InputEvent) {
+        let line = match event {
+            InputEvent::Character(char) => format!("character {:?}", char),
+            InputEvent::Special(special_key) => format!("key {:?}", special_key),
+            InputEvent::Modified(key, modifiers) => {
+                format!("key {}", key_name(key, modifiers))
+            }
+            InputEvent::Resize(size) => format!("resize to {}", size),
+            InputEvent::Mouse(mouse_event, position) => {
+                format!("mouse {:?} at {}", mouse_event, position)
+            }
+            InputEvent::Other => "unknown".to_string(),
+        };
+        self.log.push(line);
+        if self.log.len() > LOG_LENGTH {
+            self.log.remove(0);
+        }
+    }
+
+    fn update(&mut self, app: &mut App) {
+        if app.input.is_pressed(SpecialKey::Escape) {
+            app.quit();
+        }
+    }
+
+    fn render(&mut self, app: &mut App) {
+        app.draw_str(
+            Vector2Int { x: 2, y: 1 },
+            "Press any key or combination, Escape quits",
+        );
+        for (index, line) in self.log.iter().enumerate() {
+            app.draw_str(
+                Vector2Int {
+                    x: 2,
+                    y: 3 + index as i32,
+                },
+                line,
+            );
+        }
+
+        let held: Vec<String> = app
+            .input
+            .get_held_keys()
+            .iter()
+            .map(|key| key_name(*key, app.input.get_modifiers(*key).unwrap()))
+            .collect();
+        app.draw_str(
+            Vector2Int { x: 2, y: 20 },
+            &format!("held: {}", held.join(" ")),
+        );
+    }
+}
+
+/// Name of a key with its modifiers, e.g. "Ctrl+Shift+Up"
+fn key_name(key: Key, modifiers: Modifiers) -> String {
+    let mut name = String::new();
+    for (modifier, modifier_name) in [
+        (Modifiers::CTRL, "Ctrl+"),
+        (Modifiers::ALT, "Alt+"),
+        (Modifiers::SHIFT, "Shift+"),
+    ]
+    .iter()
+    {
+        if modifiers.contains(*modifier) {
+            name.push_str(modifier_name);
+        }
+    }
+    match key {
+        Key::Char(char) => name.push(char),
+        Key::Special(special_key) => name.push_str(&format!("{:?}", special_key)),
+    }
+    name
+}
+
+fn main() {
+    let mut game = KeyTester { log: Vec::new() };
+    App::new().run_game(&mut game);
+}
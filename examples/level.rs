@@ -0,0 +1,152 @@
+use lunar::{
+    app::App,
+    assets::{
+        asset_manager::Handle,
+        char_map_loader::{CharMapLoader, SpriteSheet},
+        level::Level,
+        palette::Palette,
+    },
+    entities::{char_object::CharObject, game_object::GameObject},
+    input::SpecialKey,
+    types::vector2int::Vector2Int,
+};
+
+/// Component of the player, the sheet its frames come from
+struct Player {
+    sheet: Handle<SpriteSheet>,
+    level: Handle<Level>,
+    steps: u32,
+}
+
+/// Path of a file in the examples' asset folder
+fn asset_path(name: &str) -> String {
+    format!("{}/examples/assets/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+fn main() {
+    // Create a lunar app
+    let app = App::new();
+
+    // Initialize App, load assets and spawn the level and the player
+    let init = |app: &mut App| {
+        app.set_title("Level".to_string());
+
+        // Color layers use the palette, spawn points are left undrawn
+        let palette = match app.assets.load_palette(asset_path("palette.txt")) {
+            Some(palette) => palette,
+            None => {
+                app.quit();
+                return;
+            }
+        };
+        let loader = CharMapLoader::new()
+            .set_palette(app.assets.get::<Palette>(palette).unwrap())
+            .set_transparent_char('S');
+        app.assets.set_loader(loader);
+
+        let level = app.assets.load_level(asset_path("level1.txt"));
+        let sheet = app.assets.load_sprite_sheet(asset_path("hero.txt"));
+        let (level, sheet) = match (level, sheet) {
+            (Some(level), Some(sheet)) => (level, sheet),
+            // Errors are reported once the App is done
+            _ => {
+                app.quit();
+                return;
+            }
+        };
+
+        let offset = Vector2Int { x: 2, y: 3 };
+        let tiles = app.world.spawn();
+        let level_data = app.assets.get(level).unwrap();
+        let spawn = level_data.find_tiles('S')[0] + offset;
+        let title = level_data.get_property("name").unwrap_or("").to_string();
+        let map = level_data.get_map().to_char_object();
+        app.world
+            .add_component(tiles, GameObject::new().move_to(offset));
+        app.world.add_component(tiles, map);
+        app.set_title(title);
+
+        // Loading the sheet again gives the same handle
+        assert_eq!(
+            app.assets.load_sprite_sheet(asset_path("hero.txt")),
+            Some(sheet)
+        );
+
+        let player = app.world.spawn();
+        let frame = app.assets.get(sheet).unwrap().get_frame("stand").unwrap();
+        app.world
+            .add_component(player, GameObject::new().move_to(spawn).set_layer(1));
+        app.world.add_component(player, frame.to_char_object());
+        app.world.add_component(
+            player,
+            Player {
+                sheet,
+                level,
+                steps: 0,
+            },
+        );
+    };
+
+    // Update App, move the player around the level's walls
+    let update = |app: &mut App| {
+        if app.input.is_char_key_down('q') {
+            app.quit();
+        }
+
+        let direction = match app.input.get_special_key_down() {
+            SpecialKey::Up => Vector2Int { x: 0, y: -1 },
+            SpecialKey::Down => Vector2Int { x: 0, y: 1 },
+            SpecialKey::Left => Vector2Int { x: -1, y: 0 },
+            SpecialKey::Right => Vector2Int { x: 1, y: 0 },
+            _ => return,
+        };
+        for entity in app.world.query::<(Player, GameObject)>() {
+            let player = app.world.get_component::<Player>(entity).unwrap();
+            let (sheet, level, steps) = (player.sheet, player.level, player.steps + 1);
+            let level = app.assets.get(level).unwrap();
+            let position = app
+                .world
+                .get_component::<GameObject>(entity)
+                .unwrap()
+                .get_position();
+
+            // Map positions are relative to the level's top left corner
+            let target = position + direction - Vector2Int { x: 2, y: 3 };
+            let wall = level
+                .get_property("wall")
+                .and_then(|wall| wall.chars().next());
+            let tile = level
+                .get_map()
+                .get_chars()
+                .get([target.y as usize, target.x as usize])
+                .copied();
+            if tile.is_none() || tile == wall {
+                continue;
+            }
+
+            let frame = if steps % 2 == 0 { "stand" } else { "step" };
+            let sprite: CharObject = app
+                .assets
+                .get(sheet)
+                .unwrap()
+                .get_frame(frame)
+                .unwrap()
+                .to_char_object();
+            app.world.add_component(entity, sprite);
+            app.world
+                .get_component_mut::<GameObject>(entity)
+                .unwrap()
+                .move_by(direction);
+            app.world.get_component_mut::<Player>(entity).unwrap().steps = steps;
+        }
+    };
+
+    // Entities are rendered by the App itself
+    let render = |_app: &mut App| {};
+
+    // On App exit
+    let exit = |_app: &mut App| {};
+
+    // Run App
+    app.run(init, update, render, exit);
+}
@@ -0,0 +1,108 @@
+use lunar::{
+    app::App,
+    game::Game,
+    input::{MouseButton, MouseDrag, SpecialKey},
+    rendering::cell::Cell,
+    types::{color32::Color32, vector2int::Vector2Int},
+};
+use std::collections::HashMap;
+
+const BRUSHES: [char; 5] = ['#', '*', 'o', '.', '@'];
+
+/// Paint program: left button paints, right drag fills a box, wheel picks a brush
+/// and middle click clears the canvas
+struct Paint {
+    canvas: HashMap<(i32, i32), char>,
+    brush: usize,
+}
+
+impl Paint {
+    /// Corners of the box covered by a drag, whichever way the mouse went
+    fn get_box(drag: MouseDrag) -> (Vector2Int, Vector2Int) {
+        let min = Vector2Int {
+            x: drag.start.x.min(drag.end.x),
+            y: drag.start.y.min(drag.end.y),
+        };
+        let max = Vector2Int {
+            x: drag.start.x.max(drag.end.x),
+            y: drag.start.y.max(drag.end.y),
+        };
+        (min, max)
+    }
+}
+
+impl Game for Paint {
+    fn init(&mut self, app: &mut App) {
+        app.set_title("Mouse".to_string());
+        app.set_size(Vector2Int { x: 70, y: 24 });
+    }
+
+    fn update(&mut self, app: &mut App) {
+        let input = &app.input;
+        if input.is_pressed(SpecialKey::Escape) || input.is_pressed('q') {
+            app.quit();
+            return;
+        }
+
+        let wheel = input.get_wheel_delta();
+        self.brush = (self.brush as i32 - wheel).rem_euclid(BRUSHES.len() as i32) as usize;
+
+        if input.is_mouse_down(MouseButton::Left) || input.is_mouse_pressed(MouseButton::Left) {
+            let position = input.get_mouse_position();
+            self.canvas
+                .insert((position.x, position.y), BRUSHES[self.brush]);
+        }
+        if let Some(drag) = input.get_finished_drag() {
+            if drag.button == MouseButton::Right {
+                let (min, max) = Paint::get_box(drag);
+                for y in min.y..=max.y {
+                    for x in min.x..=max.x {
+                        self.canvas.insert((x, y), BRUSHES[self.brush]);
+                    }
+                }
+            }
+        }
+        if input.is_mouse_pressed(MouseButton::Middle) {
+            self.canvas.clear();
+        }
+    }
+
+    fn render(&mut self, app: &mut App) {
+        for ((x, y), brush) in self.canvas.iter() {
+            app.draw_cell(Vector2Int { x: *x, y: *y }, Cell::new(*brush));
+        }
+
+        // Outline the box a right drag would fill
+        if let Some(drag) = app.input.get_drag() {
+            if drag.button == MouseButton::Right && app.input.is_dragging() {
+                let (min, max) = Paint::get_box(drag);
+                let outline = Cell::new('+').with_fallback(Color32::yellow(), Color32::black());
+                for x in min.x..=max.x {
+                    app.draw_cell(Vector2Int { x, y: min.y }, outline);
+                    app.draw_cell(Vector2Int { x, y: max.y }, outline);
+                }
+                for y in min.y..=max.y {
+                    app.draw_cell(Vector2Int { x: min.x, y }, outline);
+                    app.draw_cell(Vector2Int { x: max.x, y }, outline);
+                }
+            }
+        }
+
+        let position = app.input.get_mouse_position();
+        app.draw_str(
+            Vector2Int { x: 1, y: 0 },
+            &format!(
+                "mouse {} brush '{}' | left paint, right box, wheel, middle clear",
+                position, BRUSHES[self.brush]
+            ),
+        );
+    }
+}
+
+fn main() {
+    let mut game = Paint {
+        canvas: HashMap::new(),
+        brush: 0,
+    };
+    App::new().run_game(&mut game);
+}
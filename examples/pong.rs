@@ -1,73 +1,39 @@
 use lunar::{
     app::{App, Color},
-    entities::{char_object::CharObject, text_object::TextObject},
-    input::SpecialKey,
-    types::vector2int::Vector2Int,
+    assets::char_map_loader::CharMapAsset,
+    ecs::{entity::Entity, schedule::Stage, world::World},
+    entities::{char_object::CharObject, game_object::GameObject, text_object::TextObject},
+    input::{Key, SpecialKey},
+    rendering::style::Style,
+    scheduler::Sequence,
+    types::{color32::Color32, vector2int::Vector2Int},
 };
 use ndarray::array;
 use rand::Rng;
-use std::cell::Cell;
 
 const BALL_SPEED: i32 = 1;
 const PLAYER_SPEED: i32 = 1;
 
+/// Keys moving a paddle up and down
+#[derive(Clone, Copy)]
+enum Controls {
+    Letters,
+    Arrows,
+}
+
+/// Component of the paddles
+struct Paddle {
+    controls: Controls,
+}
+
+/// Component of the ball, its direction of movement
+struct Velocity(Vector2Int);
+
 fn main() {
     // Create a lunar app
     let app = App::new();
 
-    let divider_map = array![
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-        ['|'],
-    ];
-    let divider = Cell::new(CharObject::new(&divider_map));
-
-    let p1_map = array![['['], ['['], ['['], ['['], ['[']];
-    let player1 = Cell::new(CharObject::new(&p1_map));
-
-    let p2_map = array![[']'], [']'], [']'], [']'], [']']];
-    let player2 = Cell::new(CharObject::new(&p2_map));
-
-    let mut rng = rand::thread_rng();
-    let ball_direction = Cell::new(Vector2Int {
-        x: -1,
-        y: rng.gen_range(-1..2),
-    });
-    let ball_map = array![['o']];
-    let ball = Cell::new(CharObject::new(&ball_map));
-
-    let p1_score = String::from("1");
-    let player1_score_text = Cell::new(TextObject::new(&p1_score));
-    let p2_score = String::from("2");
-    let player2_score_text = Cell::new(TextObject::new(&p2_score));
-
-    // Initialize App
+    // Initialize App, spawning entities and adding the systems moving them
     let init = |app: &mut App| {
         app.set_width(101);
         app.set_height(29);
@@ -75,13 +41,82 @@ fn main() {
         app.set_background_color(Color::Black);
         app.set_foreground_color(Color::Cyan);
 
-        divider.set(divider.get().move_to(Vector2Int { x: 50, y: 0 }));
-        player1.set(player1.get().move_to(Vector2Int { x: 3, y: 12 }));
-        player2.set(player2.get().move_to(Vector2Int { x: 97, y: 12 }));
-        ball.set(ball.get().move_to(Vector2Int { x: 50, y: 14 }));
+        // Sprites hold handles to assets of the App, paddles are colored by paddles.colors
+        let assets = &mut app.assets;
+        let divider = assets
+            .load_char_map(asset_path("divider.txt"))
+            .expect("could not load divider");
+        let paddles = assets
+            .load_sprite_sheet(asset_path("paddles.txt"))
+            .expect("could not load paddles");
+        let ball_map = assets.add(CharMapAsset::new(array![['o']], None, None).unwrap());
+        let scores = [assets.add("1".to_string()), assets.add("2".to_string())];
+
+        let (assets, world) = (&app.assets, &mut app.world);
+        spawn_sprite(
+            world,
+            CharObject::from_asset(assets, divider).unwrap(),
+            Vector2Int { x: 50, y: 0 },
+        );
+
+        let player1 = spawn_sprite(
+            world,
+            CharObject::from_frame(assets, paddles, "left").unwrap(),
+            Vector2Int { x: 3, y: 12 },
+        );
+        world.add_component(
+            player1,
+            Paddle {
+                controls: Controls::Letters,
+            },
+        );
+        let player2 = spawn_sprite(
+            world,
+            CharObject::from_frame(assets, paddles, "right").unwrap(),
+            Vector2Int { x: 97, y: 12 },
+        );
+        world.add_component(
+            player2,
+            Paddle {
+                controls: Controls::Arrows,
+            },
+        );
+
+        // Ball is on a higher layer so it is always drawn over the divider
+        let ball = world.spawn();
+        world.add_component(
+            ball,
+            GameObject::new()
+                .move_to(Vector2Int { x: 50, y: 14 })
+                .set_layer(1),
+        );
+        world.add_component(
+            ball,
+            CharObject::from_asset(assets, ball_map)
+                .unwrap()
+                .set_foreground_color(Color32::from_hex("ffaf00")),
+        );
+        world.add_component(
+            ball,
+            Velocity(Vector2Int {
+                x: -1,
+                y: rand::thread_rng().gen_range(-1..2),
+            }),
+        );
+
+        for (text, x) in [(scores[0], 25), (scores[1], 75)].iter() {
+            let score = world.spawn();
+            world.add_component(score, GameObject::new().move_to(Vector2Int { x: *x, y: 1 }));
+            world.add_component(
+                score,
+                TextObject::from_asset(assets, *text)
+                    .unwrap()
+                    .set_style(Style::BOLD),
+            );
+        }
 
-        player1_score_text.set(player1_score_text.get().move_to(Vector2Int { x: 25, y: 1 }));
-        player2_score_text.set(player2_score_text.get().move_to(Vector2Int { x: 75, y: 1 }));
+        app.add_system(Stage::Update, move_paddles);
+        app.add_system(Stage::Update, move_ball);
     };
 
     // Update App
@@ -89,64 +124,110 @@ fn main() {
         if app.input.is_char_key_down('q') {
             app.quit();
         }
+    };
 
-        // Move player 1 using W/S keys
-        if app.input.is_char_key_down('w') && player1.get().get_position().y > 1 {
-            player1.set(player1.get().move_by(Vector2Int::down() * PLAYER_SPEED));
-        }
-        if app.input.is_char_key_down('s') && player1.get().get_position().y < 23 {
-            player1.set(player1.get().move_by(Vector2Int::up() * PLAYER_SPEED));
-        }
+    // Entities are rendered by the App itself
+    let render = |_app: &mut App| {};
+
+    // On App exit
+    let exit = |_app: &mut App| {};
 
-        // Move player 2 using arrow keys
-        if app.input.is_special_key_down(SpecialKey::Up) && player2.get().get_position().y > 1 {
-            player2.set(player2.get().move_by(Vector2Int::down() * PLAYER_SPEED));
+    // Run App
+    app.run(init, update, render, exit);
+}
+
+/// Path of a file in the examples' asset folder
+fn asset_path(name: &str) -> String {
+    format!("{}/examples/assets/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+/// Spawn an entity drawn with a sprite at a position
+fn spawn_sprite(world: &mut World, sprite: CharObject, position: Vector2Int) -> Entity {
+    let entity = world.spawn();
+    world.add_component(entity, GameObject::new().move_to(position));
+    world.add_component(entity, sprite);
+    entity
+}
+
+/// A tap moves a paddle once, holding the key moves it as long as the terminal repeats it
+fn is_moving(app: &App, key: impl Into<Key> + Copy) -> bool {
+    app.input.is_pressed(key) || app.input.is_repeating(key)
+}
+
+/// Move paddles with W/S keys or arrow keys, both players can hold their keys at once
+fn move_paddles(app: &mut App) {
+    for entity in app.world.query::<(Paddle, GameObject)>() {
+        let (up, down) = match app.world.get_component::<Paddle>(entity).unwrap().controls {
+            Controls::Letters => (is_moving(app, 'w'), is_moving(app, 's')),
+            Controls::Arrows => (
+                is_moving(app, SpecialKey::Up),
+                is_moving(app, SpecialKey::Down),
+            ),
+        };
+
+        let game_object = app.world.get_component_mut::<GameObject>(entity).unwrap();
+        if up && game_object.get_position().y > 1 {
+            game_object.move_by(Vector2Int::down() * PLAYER_SPEED);
         }
-        if app.input.is_special_key_down(SpecialKey::Down) && player2.get().get_position().y < 23 {
-            player2.set(player2.get().move_by(Vector2Int::up() * PLAYER_SPEED));
+        if down && game_object.get_position().y < 23 {
+            game_object.move_by(Vector2Int::up() * PLAYER_SPEED);
         }
+    }
+}
+
+/// Move the ball and bounce it off the edges of the screen
+fn move_ball(app: &mut App) {
+    let mut rng = rand::thread_rng();
+    for entity in app.world.query::<(Velocity, GameObject)>() {
+        let direction = app.world.get_component::<Velocity>(entity).unwrap().0;
+        let position = app
+            .world
+            .get_component_mut::<GameObject>(entity)
+            .unwrap()
+            .move_by(direction * BALL_SPEED)
+            .get_position();
 
-        // Move ball
-        ball.set(ball.get().move_by(ball_direction.get() * BALL_SPEED));
         // Handle ball bounce
-        if ball.get().get_position().y <= 1 {
-            ball_direction.set(Vector2Int {
+        let mut direction = direction;
+        if position.y <= 1 {
+            direction = Vector2Int {
                 x: rng.gen_range(-1..2),
                 y: 1,
-            });
-        } else if ball.get().get_position().y >= 28 {
-            ball_direction.set(Vector2Int {
+            };
+        } else if position.y >= 28 {
+            direction = Vector2Int {
                 x: rng.gen_range(-1..2),
                 y: -1,
-            });
+            };
         }
-        if ball.get().get_position().x <= 1 {
-            ball_direction.set(Vector2Int {
-                x: 1,
-                y: rng.gen_range(-1..2),
-            });
-        } else if ball.get().get_position().x >= 100 {
-            ball_direction.set(Vector2Int {
-                x: -1,
-                y: rng.gen_range(-1..2),
-            });
+        // Ball went past a paddle, stop it and serve it again from the middle
+        if position.x <= 1 || position.x >= 100 {
+            app.world.remove_component::<Velocity>(entity);
+            respawn_ball(app, entity, if position.x <= 1 { 1 } else { -1 });
+            continue;
         }
-    };
-
-    // Render objects on App window
-    let render = |app: &mut App| {
-        divider.get().render(app);
-        player1.get().render(app);
-        player2.get().render(app);
-        ball.get().render(app);
-
-        player1_score_text.get().render(app);
-        player2_score_text.get().render(app);
-    };
-
-    // On App exit
-    let exit = |_app: &mut App| {};
+        app.world.get_component_mut::<Velocity>(entity).unwrap().0 = direction;
+    }
+}
 
-    // Run App
-    app.run(init, update, render, exit);
+/// Flash the screen then serve the ball towards a side after a short delay
+fn respawn_ball(app: &mut App, ball: Entity, side: i32) {
+    app.scheduler.run(
+        Sequence::new()
+            .wait(1.0)
+            .then(|app: &mut App| app.flash())
+            .wait(0.5)
+            .then(move |app: &mut App| {
+                if let Some(game_object) = app.world.get_component_mut::<GameObject>(ball) {
+                    game_object.move_to(Vector2Int { x: 50, y: 14 });
+                }
+                app.world.add_component(
+                    ball,
+                    Velocity(Vector2Int {
+                        x: side,
+                        y: rand::thread_rng().gen_range(-1..2),
+                    }),
+                );
+            }),
+    );
 }
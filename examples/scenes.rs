@@ -0,0 +1,119 @@
+use lunar::{
+    app::App, entities::char_object::CharObject, input::SpecialKey, scene::Scene,
+    types::vector2int::Vector2Int,
+};
+use ndarray::{array, Array2};
+
+/// Title screen, space starts the game
+struct Title;
+
+impl Scene for Title {
+    fn update(&mut self, app: &mut App) {
+        if app.input.is_pressed(' ') {
+            app.replace_scene(Game::new());
+        }
+    }
+
+    fn render(&mut self, app: &mut App) {
+        app.draw_str(Vector2Int { x: 44, y: 12 }, "lunar scenes");
+        app.draw_str(Vector2Int { x: 40, y: 15 }, "press space to start");
+    }
+}
+
+/// Gameplay, arrow keys move the player and P pauses
+struct Game {
+    player_map: Array2<char>,
+    position: Vector2Int,
+}
+
+impl Game {
+    fn new() -> Self {
+        Self {
+            player_map: array![['@']],
+            position: Vector2Int { x: 50, y: 15 },
+        }
+    }
+}
+
+impl Scene for Game {
+    fn update(&mut self, app: &mut App) {
+        if app.input.is_pressed('p') {
+            app.push_scene(Pause);
+        }
+        if app.input.is_special_key_down(SpecialKey::Left) {
+            self.position += Vector2Int::left();
+        }
+        if app.input.is_special_key_down(SpecialKey::Right) {
+            self.position += Vector2Int::right();
+        }
+    }
+
+    fn render(&mut self, app: &mut App) {
+        CharObject::new(&self.player_map)
+            .move_to(self.position)
+            .render(app);
+    }
+}
+
+/// Pause menu drawn over the game, which keeps being rendered underneath
+struct Pause;
+
+impl Scene for Pause {
+    fn enter(&mut self, app: &mut App) {
+        app.set_title("Scenes (paused)".to_string());
+    }
+
+    fn exit(&mut self, app: &mut App) {
+        app.set_title("Scenes".to_string());
+    }
+
+    fn update(&mut self, app: &mut App) {
+        if app.input.is_pressed('r') {
+            app.pop_scene();
+        }
+        if app.input.is_pressed('t') {
+            app.clear_scenes();
+            app.push_scene(Title);
+        }
+    }
+
+    fn render(&mut self, app: &mut App) {
+        app.draw_str(
+            Vector2Int { x: 36, y: 10 },
+            "paused: R resumes, T goes to title",
+        );
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
+
+fn main() {
+    // Create a lunar app
+    let mut app = App::new();
+
+    // Start on the title screen
+    app.push_scene(Title);
+
+    // Initialize App
+    let init = |app: &mut App| {
+        app.set_title("Scenes".to_string());
+    };
+
+    // Update App
+    let update = |app: &mut App| {
+        if app.input.is_char_key_down('q') {
+            app.quit();
+        }
+    };
+
+    // Scenes render themselves
+    let render = |_app: &mut App| {};
+
+    // On App exit
+    let exit = |_app: &mut App| {};
+
+    // Run App
+    app.run(init, update, render, exit);
+}
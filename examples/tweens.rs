@@ -0,0 +1,84 @@
+use lunar::{
+    app::App,
+    entities::{char_object::CharObject, game_object::GameObject},
+    tweening::{
+        easing::Easing,
+        tween::{Tween, TweenMode},
+    },
+    types::{color::Color, vector2int::Vector2Int},
+};
+use ndarray::array;
+
+fn main() {
+    // Create a lunar app
+    let app = App::new();
+
+    let marker_map = array![['o']];
+    let easings = [
+        ("Linear", Easing::Linear),
+        ("QuadInOut", Easing::QuadInOut),
+        ("CubicOut", Easing::CubicOut),
+        ("SineInOut", Easing::SineInOut),
+        ("BackOut", Easing::BackOut),
+        ("ElasticOut", Easing::ElasticOut),
+        ("BounceOut", Easing::BounceOut),
+    ];
+
+    // Initialize App, one marker going back and forth per easing curve
+    let init = |app: &mut App| {
+        app.set_title("Tweens".to_string());
+
+        for (row, (_, easing)) in easings.iter().enumerate() {
+            let from = Vector2Int {
+                x: 16,
+                y: 3 + row as i32 * 3,
+            };
+            let to = Vector2Int { x: 90, y: from.y };
+
+            let marker = app.world.spawn();
+            app.world
+                .add_component(marker, GameObject::new().move_to(from));
+            app.world
+                .add_component(marker, CharObject::new(&marker_map));
+            app.tweens.add(
+                Tween::move_entity(marker, from, to, 2.0)
+                    .set_easing(*easing)
+                    .set_mode(TweenMode::Yoyo),
+            );
+        }
+
+        // Fade the text color in, then say so
+        app.tweens.add(
+            Tween::new(Color::black(), Color::white(), 3.0)
+                .set_easing(Easing::QuadIn)
+                .on_update(|app: &mut App, color| app.set_foreground_color(color))
+                .on_complete(|app: &mut App| app.set_title("Tweens (faded in)".to_string())),
+        );
+    };
+
+    // Update App
+    let update = |app: &mut App| {
+        if app.input.is_char_key_down('q') {
+            app.quit();
+        }
+    };
+
+    // Render easing names, markers are rendered by the App itself
+    let render = |app: &mut App| {
+        for (row, (name, _)) in easings.iter().enumerate() {
+            app.draw_str(
+                Vector2Int {
+                    x: 2,
+                    y: 3 + row as i32 * 3,
+                },
+                name,
+            );
+        }
+    };
+
+    // On App exit
+    let exit = |_app: &mut App| {};
+
+    // Run App
+    app.run(init, update, render, exit);
+}
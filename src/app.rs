@@ -1,13 +1,34 @@
 use std::{thread::sleep, time::Duration};
 
-use crate::{input::Input, types::vector2int::Vector2Int};
+use crate::{
+    assets::asset_manager::AssetManager,
+    backend::{pancurses_backend::PancursesBackend, Backend},
+    ecs::{
+        schedule::{Schedule, Stage},
+        world::World,
+    },
+    entities::{animated_object::AnimatedObject, char_object::CharObject, text_object::TextObject},
+    game::{ClosureGame, Game},
+    input::{Input, InputEvent},
+    rendering::{
+        cell::Cell,
+        color_depth::ColorDepth,
+        frame_buffer::FrameBuffer,
+        render_queue::{RenderQueue, Renderable},
+        style::Style,
+        viewport::Viewport,
+    },
+    scene::{Scene, SceneCommand, SceneStack},
+    scheduler::Scheduler,
+    time::Time,
+    tweening::tweens::Tweens,
+    types::{color32::Color32, rect_int::RectInt, vector2int::Vector2Int},
+};
 use pancurses::{
-    beep, curs_set, endwin, flash, has_colors, init_pair, initscr, mousemask, noecho, resize_term,
-    set_title, start_color, Window, ALL_MOUSE_EVENTS, COLOR_BLACK, COLOR_CYAN, COLOR_GREEN,
-    COLOR_MAGENTA, COLOR_PAIR, COLOR_RED, COLOR_WHITE, COLOR_YELLOW, REPORT_MOUSE_POSITION,
+    COLOR_BLACK, COLOR_CYAN, COLOR_GREEN, COLOR_MAGENTA, COLOR_RED, COLOR_WHITE, COLOR_YELLOW,
 };
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 /// How to display the console cursor
 pub enum CursorMode {
     Hidden = 0,
@@ -15,7 +36,7 @@ pub enum CursorMode {
     Block = 2,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum Color {
     Black = COLOR_BLACK as isize,
     Cyan = COLOR_CYAN as isize,
@@ -26,6 +47,20 @@ pub enum Color {
     Yellow = COLOR_YELLOW as isize,
 }
 
+impl From<Color> for Color32 {
+    fn from(color: Color) -> Color32 {
+        return match color {
+            Color::Black => Color32::black(),
+            Color::Cyan => Color32::teal(),
+            Color::Green => Color32::green(),
+            Color::Magenta => Color32::pink(),
+            Color::Red => Color32::red(),
+            Color::White => Color32::white(),
+            Color::Yellow => Color32::yellow(),
+        };
+    }
+}
+
 // Default values for window initialization
 pub const DEFAULT_WIDTH: i32 = 100;
 pub const DEFAULT_HEIGHT: i32 = 30;
@@ -38,6 +73,9 @@ pub const DEFAULT_BORDER: u64 = 0;
 pub const DEFAULT_CORNER: u64 = 0;
 pub const DEFAULT_FRAMERATE: u32 = 60;
 
+// Events read in a single frame at most, the rest waits for the next frame
+const MAX_EVENTS_PER_FRAME: usize = 1024;
+
 /// Main program App, everything is wrapped in here
 pub struct App {
     // Window properties
@@ -45,8 +83,8 @@ pub struct App {
     height: i32,
     title: String,
     background: u64,
-    background_color: Color,
-    foreground_color: Color,
+    background_color: Color32,
+    foreground_color: Color32,
     cursor_mode: CursorMode,
     frame_time: f32,
 
@@ -63,20 +101,55 @@ pub struct App {
     corner_bottom_right: u64,
 
     do_quit: bool,
-    pub window: Window,
+    backend: Box<dyn Backend>,
+
+    // Frame buffers, entities draw into the back buffer and only
+    // cells that differ from the front buffer are sent to the backend
+    front_buffer: FrameBuffer,
+    back_buffer: FrameBuffer,
+    cells_written: usize,
+    render_queue: RenderQueue,
+
+    // Viewports showing the world through cameras, and the one being drawn into
+    viewports: Vec<Viewport>,
+    current_viewport: Option<Viewport>,
+
+    // Game entities and the systems going through them every frame
+    pub world: World,
+    schedule: Schedule,
+    // Set when systems are cleared while they run, so the running ones are not restored
+    do_clear_systems: bool,
+
+    // Scenes, only the top one is updated
+    scenes: SceneStack,
+
     pub input: Input,
+    pub time: Time,
+    pub scheduler: Scheduler,
+    pub tweens: Tweens,
+    pub assets: AssetManager,
 }
 
 impl App {
-    /// Default constructor to initialize App
+    /// Default constructor to initialize App on the current terminal through curses,
+    /// which draws up to 256 colors, see `AnsiBackend::terminal` for 24-bit colors
     pub fn new() -> Self {
+        return Self::with_backend(Box::new(PancursesBackend::new()));
+    }
+
+    /// Constructor to initialize App on a custom backend
+    pub fn with_backend(backend: Box<dyn Backend>) -> Self {
+        let default_size = Vector2Int {
+            x: DEFAULT_WIDTH,
+            y: DEFAULT_HEIGHT,
+        };
         return Self {
             width: DEFAULT_WIDTH,
             height: DEFAULT_HEIGHT,
             title: String::from(DEFAULT_TITLE),
             background: DEFAULT_BACKGROUND,
-            background_color: DEFAULT_BACKGROUND_COLOR,
-            foreground_color: DEFAULT_FOREGROUND_COLOR,
+            background_color: Color32::from(DEFAULT_BACKGROUND_COLOR),
+            foreground_color: Color32::from(DEFAULT_FOREGROUND_COLOR),
             cursor_mode: DEFAULT_CURSOR_MODE,
             frame_time: 1 as f32 / DEFAULT_FRAMERATE as f32,
 
@@ -91,8 +164,27 @@ impl App {
             corner_bottom_right: DEFAULT_CORNER,
 
             do_quit: false,
-            window: initscr(),
+            backend,
+
+            front_buffer: FrameBuffer::new(default_size, Cell::blank()),
+            back_buffer: FrameBuffer::new(default_size, Cell::blank()),
+            cells_written: 0,
+            render_queue: RenderQueue::new(),
+
+            viewports: Vec::new(),
+            current_viewport: None,
+
+            world: World::new(),
+            schedule: Schedule::new(),
+            do_clear_systems: false,
+
+            scenes: SceneStack::new(),
+
             input: Input::new(),
+            time: Time::new(1 as f32 / DEFAULT_FRAMERATE as f32),
+            scheduler: Scheduler::new(),
+            tweens: Tweens::new(),
+            assets: AssetManager::new(),
         };
     }
 
@@ -121,7 +213,7 @@ impl App {
     /// Set App console window width
     pub fn set_width(&mut self, width: i32) {
         self.width = width;
-        resize_term(self.height, self.width);
+        self.resize_buffers();
     }
 
     /// Get App console window width
@@ -132,7 +224,7 @@ impl App {
     /// Set App console window height
     pub fn set_height(&mut self, height: i32) {
         self.height = height;
-        resize_term(self.height, self.width);
+        self.resize_buffers();
     }
 
     /// Get App console height
@@ -144,7 +236,7 @@ impl App {
     pub fn set_size(&mut self, size: Vector2Int) {
         self.width = size.x;
         self.height = size.y;
-        resize_term(self.height, self.width);
+        self.resize_buffers();
     }
 
     /// Return current App's console window size as a Vector2Int
@@ -157,7 +249,8 @@ impl App {
 
     /// Set App console window title
     pub fn set_title(&mut self, title: String) {
-        set_title(&title);
+        self.backend.set_title(&title);
+        self.title = title;
     }
 
     /// Get App console window title
@@ -168,7 +261,7 @@ impl App {
     /// Set current App's cursor display mode
     pub fn set_cursor_mode(&mut self, mode: CursorMode) {
         self.cursor_mode = mode;
-        curs_set(self.cursor_mode as i32);
+        self.backend.set_cursor_mode(self.cursor_mode);
     }
 
     /// Get current App's cursor display mode
@@ -179,15 +272,13 @@ impl App {
     /// Set current App's background character
     pub fn set_background_char(&mut self, background_char: char) {
         self.background = background_char as u64;
-        self.window.bkgdset(self.background);
-        self.window.clear();
+        self.clear();
     }
 
     /// Set current App's background to empty
     pub fn clear_background(&mut self) {
         self.background = DEFAULT_BACKGROUND;
-        self.window.bkgdset(self.background);
-        self.window.clear();
+        self.clear();
     }
 
     /// Get current App's background character if there is a background set
@@ -196,50 +287,78 @@ impl App {
     }
 
     /// Set current App's background color
-    pub fn set_background_color(&mut self, background_color: Color) {
-        self.background_color = background_color;
-        init_pair(
-            2,
-            self.foreground_color as i16,
-            self.background_color as i16,
-        );
-        self.window.bkgd(COLOR_PAIR(1));
+    ///
+    /// Accepts a Color, a Color32 or a types::color::Color
+    pub fn set_background_color(&mut self, background_color: impl Into<Color32>) {
+        self.background_color = background_color.into();
     }
 
     /// Get current App's background color
-    pub fn get_background_color(&self) -> Color {
+    pub fn get_background_color(&self) -> Color32 {
         return self.background_color;
     }
 
     /// Set current App's foreground color
-    pub fn set_foreground_color(&mut self, foreground_color: Color) {
-        self.foreground_color = foreground_color;
-        init_pair(
-            2,
-            self.foreground_color as i16,
-            self.background_color as i16,
-        );
-        self.window.attron(COLOR_PAIR(2));
+    ///
+    /// Accepts a Color, a Color32 or a types::color::Color
+    pub fn set_foreground_color(&mut self, foreground_color: impl Into<Color32>) {
+        self.foreground_color = foreground_color.into();
     }
 
     /// Get current App's foreground color
-    pub fn get_foreground_color(&self) -> Color {
+    pub fn get_foreground_color(&self) -> Color32 {
         return self.foreground_color;
     }
 
+    /// Get how many colors the App's backend is able to display
+    pub fn get_color_depth(&self) -> ColorDepth {
+        return self.backend.get_color_depth();
+    }
+
     //#region
-    /// Update all App's borders and corners based on current border values
+    /// Draw all App's borders and corners based on current border values
     fn update_borders_corners(&mut self) {
-        self.window.border(
-            self.border_left,
-            self.border_right,
-            self.border_top,
-            self.border_bottom,
-            self.corner_top_left,
-            self.corner_top_right,
-            self.corner_bottom_left,
-            self.corner_bottom_right,
-        );
+        let right = self.width - 1;
+        let bottom = self.height - 1;
+        let left_border = self.get_glyph(self.border_left, '│');
+        let right_border = self.get_glyph(self.border_right, '│');
+        let top_border = self.get_glyph(self.border_top, '─');
+        let bottom_border = self.get_glyph(self.border_bottom, '─');
+
+        for y in 1..bottom {
+            self.draw_char(Vector2Int { x: 0, y }, left_border);
+            self.draw_char(Vector2Int { x: right, y }, right_border);
+        }
+        for x in 1..right {
+            self.draw_char(Vector2Int { x, y: 0 }, top_border);
+            self.draw_char(Vector2Int { x, y: bottom }, bottom_border);
+        }
+
+        let corners = [
+            (Vector2Int { x: 0, y: 0 }, self.corner_top_left, '┌'),
+            (Vector2Int { x: right, y: 0 }, self.corner_top_right, '┐'),
+            (Vector2Int { x: 0, y: bottom }, self.corner_bottom_left, '└'),
+            (
+                Vector2Int {
+                    x: right,
+                    y: bottom,
+                },
+                self.corner_bottom_right,
+                '┘',
+            ),
+        ];
+        for (position, corner, default) in corners.iter() {
+            let glyph = self.get_glyph(*corner, *default);
+            self.draw_char(*position, glyph);
+        }
+    }
+
+    /// Convert a stored border value to a glyph, 0 meaning a default line
+    fn get_glyph(&self, value: u64, default: char) -> char {
+        if value == 0 {
+            return default;
+        }
+        return std::char::from_u32(value as u32).unwrap_or(default);
     }
 
     /// Set current App's left side border
@@ -384,19 +503,352 @@ impl App {
     ///
     /// Warning: may cause seizure, please use with caution
     pub fn flash(&self) {
-        flash();
+        self.backend.flash();
     }
 
     /// Play native OS's beep sound
     ///
     /// Warning: can be annoying, please use with caution
     pub fn beep(&self) {
-        beep();
+        self.backend.beep();
     }
 
     /// Clear the entire screen
-    pub fn clear(&self) {
-        self.window.clear();
+    pub fn clear(&mut self) {
+        let blank = self.get_blank_cell();
+        self.back_buffer.fill(blank);
+    }
+
+    /// Get the cell empty space is filled with
+    pub fn get_blank_cell(&self) -> Cell {
+        return Cell {
+            glyph: self.get_glyph(self.background, ' '),
+            foreground: self.foreground_color,
+            background: self.background_color,
+            style: Style::NONE,
+        };
+    }
+
+    /// Draw a single cell on App window
+    ///
+    /// While queued objects are drawn into a viewport, positions are world coordinates
+    /// and cells outside of the viewport are clipped
+    pub fn draw_cell(&mut self, position: Vector2Int, cell: Cell) {
+        if let Some(viewport) = self.current_viewport {
+            let screen_position = viewport.world_to_screen(position);
+            if viewport.rect.contains(screen_position) {
+                self.back_buffer.set(screen_position, cell);
+            }
+            return;
+        }
+        self.back_buffer.set(position, cell);
+    }
+
+    /// Draw a single character on App window in current colors
+    pub fn draw_char(&mut self, position: Vector2Int, glyph: char) {
+        let cell = Cell {
+            glyph,
+            ..self.get_blank_cell()
+        };
+        self.draw_cell(position, cell);
+    }
+
+    /// Draw a string on App window in current colors
+    pub fn draw_str(&mut self, position: Vector2Int, text: &str) {
+        for (offset, glyph) in text.chars().enumerate() {
+            self.draw_char(
+                Vector2Int {
+                    x: position.x + offset as i32,
+                    y: position.y,
+                },
+                glyph,
+            );
+        }
+    }
+
+    /// Queue an object to be drawn after the render step, ordered by layer and z-index
+    pub fn submit(&mut self, item: impl Renderable + 'static) {
+        self.render_queue.submit(Box::new(item));
+    }
+
+    /// Set whether objects within a layer are drawn from top to bottom of the screen,
+    /// for top-down games where lower objects stand in front of higher ones
+    pub fn set_layer_y_sorted(&mut self, layer: i32, value: bool) {
+        self.render_queue.set_layer_y_sorted(layer, value);
+    }
+
+    /// Check if objects within a layer are sorted by their bottom edge
+    pub fn is_layer_y_sorted(&self, layer: i32) -> bool {
+        return self.render_queue.is_layer_y_sorted(layer);
+    }
+
+    /// Set whether objects within a layer are placed in screen coordinates, ignoring
+    /// viewports and cameras (for HUDs and menus)
+    pub fn set_layer_screen_space(&mut self, layer: i32, value: bool) {
+        self.render_queue.set_layer_screen_space(layer, value);
+    }
+
+    /// Check if objects within a layer are placed in screen coordinates
+    pub fn is_layer_screen_space(&self, layer: i32) -> bool {
+        return self.render_queue.is_layer_screen_space(layer);
+    }
+
+    /// Add a viewport showing the world through its camera, returning its index
+    ///
+    /// Without viewports, world coordinates are screen coordinates
+    pub fn add_viewport(&mut self, viewport: Viewport) -> usize {
+        self.viewports.push(viewport);
+        return self.viewports.len() - 1;
+    }
+
+    /// Get a viewport by index
+    pub fn get_viewport(&self, index: usize) -> Option<&Viewport> {
+        return self.viewports.get(index);
+    }
+
+    /// Get a viewport by index to move its camera or area
+    pub fn get_viewport_mut(&mut self, index: usize) -> Option<&mut Viewport> {
+        return self.viewports.get_mut(index);
+    }
+
+    /// Remove all viewports
+    pub fn clear_viewports(&mut self) {
+        self.viewports.clear();
+    }
+
+    /// Update every viewport's camera
+    fn update_cameras(&mut self) {
+        for viewport in self.viewports.iter_mut() {
+            viewport.update();
+        }
+    }
+
+    /// Draw every queued object in order, through every viewport
+    fn flush_render_queue(&mut self) {
+        for item in self.render_queue.take_sorted() {
+            let game_object = item.get_game_object();
+            if self.viewports.is_empty()
+                || self
+                    .render_queue
+                    .is_layer_screen_space(game_object.get_layer())
+            {
+                item.draw(self);
+                continue;
+            }
+
+            // Draw into each viewport, skipping ones the object is not visible in
+            let bounds = RectInt::new(game_object.get_position(), item.get_size());
+            for index in 0..self.viewports.len() {
+                let viewport = self.viewports[index];
+                if !viewport.is_visible(bounds) {
+                    continue;
+                }
+                self.current_viewport = Some(viewport);
+                item.draw(self);
+            }
+            self.current_viewport = None;
+        }
+    }
+
+    /// Add a system run every frame at a stage, after the systems already added
+    pub fn add_system(&mut self, stage: Stage, system: impl FnMut(&mut App) + 'static) {
+        self.schedule.add_system(stage, Box::new(system));
+    }
+
+    /// Remove all systems
+    pub fn clear_systems(&mut self) {
+        self.schedule.clear();
+        self.do_clear_systems = true;
+    }
+
+    /// Run every system of a stage, keeping systems added meanwhile for the next frames
+    fn run_systems(&mut self, stage: Stage) {
+        self.do_clear_systems = false;
+        let mut schedule = std::mem::replace(&mut self.schedule, Schedule::new());
+        schedule.run(stage, self);
+        // Systems cleared while running are dropped, keeping only those added after
+        if self.do_clear_systems {
+            return;
+        }
+        schedule.append(&mut self.schedule);
+        self.schedule = schedule;
+    }
+
+    /// Play every entity's AnimatedObject by the frame's delta time
+    fn update_animations(&mut self) {
+        let delta = self.time.get_delta();
+        for entity in self.world.query::<(AnimatedObject,)>() {
+            if let Some(animated_object) = self.world.get_component_mut::<AnimatedObject>(entity) {
+                animated_object.update(delta);
+            }
+        }
+    }
+
+    /// Queue every entity having a CharObject, an AnimatedObject or a TextObject,
+    /// placed by its GameObject
+    ///
+    /// Children are placed at their world position and hidden along with their parents,
+    /// see `World::place_game_object`
+    fn render_world(&mut self) {
+        for entity in self.world.query::<(CharObject,)>() {
+            let mut sprite = *self.world.get_component::<CharObject>(entity).unwrap();
+            sprite.set_game_object(
+                self.world
+                    .place_game_object(entity, &sprite.get_game_object()),
+            );
+            self.submit(sprite);
+        }
+        for entity in self.world.query::<(AnimatedObject,)>() {
+            let animated_object = self.world.get_component::<AnimatedObject>(entity).unwrap();
+            let mut sprite = animated_object.get_frame_sprite();
+            sprite.set_game_object(
+                self.world
+                    .place_game_object(entity, &sprite.get_game_object()),
+            );
+            self.submit(sprite);
+        }
+        for entity in self.world.query::<(TextObject,)>() {
+            let mut text = *self.world.get_component::<TextObject>(entity).unwrap();
+            text.set_game_object(
+                self.world
+                    .place_game_object(entity, &text.get_game_object()),
+            );
+            self.submit(text);
+        }
+    }
+
+    /// Put a scene on top of the stack, pausing the current one
+    ///
+    /// Scene changes are applied once the current scene is done updating
+    pub fn push_scene(&mut self, scene: impl Scene + 'static) {
+        self.scenes.queue(SceneCommand::Push(Box::new(scene)));
+    }
+
+    /// Take the scene on top of the stack off, resuming the one underneath
+    pub fn pop_scene(&mut self) {
+        self.scenes.queue(SceneCommand::Pop);
+    }
+
+    /// Swap the scene on top of the stack for another one
+    pub fn replace_scene(&mut self, scene: impl Scene + 'static) {
+        self.scenes.queue(SceneCommand::Replace(Box::new(scene)));
+    }
+
+    /// Take every scene off the stack
+    pub fn clear_scenes(&mut self) {
+        self.scenes.queue(SceneCommand::Clear);
+    }
+
+    /// Get number of scenes on the stack
+    pub fn get_scene_count(&self) -> usize {
+        return self.scenes.len();
+    }
+
+    /// Update the scene on top of the stack then apply scene changes
+    fn update_scenes(&mut self) {
+        if let Some(mut scene) = self.scenes.pop() {
+            scene.update(self);
+            self.scenes.push(scene);
+        }
+        self.apply_scene_commands();
+    }
+
+    /// Render the scene on top of the stack, and the ones underneath it while it is an overlay
+    fn render_scenes(&mut self) {
+        let first = self.scenes.get_first_visible();
+        let mut visible = self.scenes.split_off(first);
+        for scene in visible.iter_mut() {
+            scene.render(self);
+        }
+        self.scenes.append(&mut visible);
+    }
+
+    /// Apply queued scene changes, including the ones queued by enter and exit hooks
+    fn apply_scene_commands(&mut self) {
+        while self.scenes.has_commands() {
+            for command in self.scenes.take_commands() {
+                match command {
+                    SceneCommand::Push(mut scene) => {
+                        if let Some(mut top) = self.scenes.pop() {
+                            top.pause(self);
+                            self.scenes.push(top);
+                        }
+                        scene.enter(self);
+                        self.scenes.push(scene);
+                    }
+                    SceneCommand::Pop => {
+                        if let Some(mut top) = self.scenes.pop() {
+                            top.exit(self);
+                        }
+                        if let Some(mut top) = self.scenes.pop() {
+                            top.resume(self);
+                            self.scenes.push(top);
+                        }
+                    }
+                    SceneCommand::Replace(mut scene) => {
+                        if let Some(mut top) = self.scenes.pop() {
+                            top.exit(self);
+                        }
+                        scene.enter(self);
+                        self.scenes.push(scene);
+                    }
+                    SceneCommand::Clear => {
+                        while let Some(mut top) = self.scenes.pop() {
+                            top.exit(self);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Advance scheduled timers by the frame's delta time, callbacks may schedule
+    /// or cancel timers themselves
+    fn update_scheduler(&mut self) {
+        Scheduler::update(self, self.time.get_delta());
+    }
+
+    /// Advance running tweens by the frame's delta time, callbacks may add or cancel
+    /// tweens themselves
+    fn update_tweens(&mut self) {
+        Tweens::update(self, self.time.get_delta());
+    }
+
+    /// Get a cell of the frame currently being drawn
+    pub fn get_cell(&self, position: Vector2Int) -> Option<Cell> {
+        return self.back_buffer.get(position);
+    }
+
+    /// Get number of cells sent to the backend when the last frame was presented
+    pub fn get_cells_written(&self) -> usize {
+        return self.cells_written;
+    }
+
+    /// Resize backend and frame buffers to current App size
+    fn resize_buffers(&mut self) {
+        let size = self.get_size();
+        let blank = self.get_blank_cell();
+        self.backend.set_size(size);
+        self.back_buffer.resize(size, blank);
+        self.front_buffer.resize(size, blank);
+        self.invalidate();
+    }
+
+    /// Force every cell to be sent to the backend on the next present
+    fn invalidate(&mut self) {
+        self.front_buffer.fill(Cell::new('\0'));
+        self.backend.clear();
+    }
+
+    /// Send cells that changed since the last frame to the backend and show them
+    fn present(&mut self) {
+        self.cells_written = 0;
+        for (position, cell) in self.back_buffer.diff(&self.front_buffer) {
+            self.backend.draw_cell(position, cell);
+            self.cells_written += 1;
+        }
+        self.backend.present();
+        std::mem::swap(&mut self.front_buffer, &mut self.back_buffer);
     }
 
     /// Quit current App
@@ -404,24 +856,21 @@ impl App {
         self.do_quit = true;
     }
 
-    /// Run current App
-    pub fn run<I, U, R, E>(mut self, mut init: I, mut update: U, mut render: R, mut exit: E)
+    /// Run current App with four closures, see `run_game` to run a Game instead
+    pub fn run<I, U, R, E>(self, init: I, update: U, render: R, exit: E)
     where
         I: FnMut(&mut App) -> (),
         U: FnMut(&mut App) -> (),
         R: FnMut(&mut App) -> (),
         E: FnMut(&mut App) -> (),
     {
-        // Initialize current window and set default values
-        self.window.keypad(true);
-        self.window.nodelay(true);
-
-        // Listen to all mouse events
-        mousemask(ALL_MOUSE_EVENTS | REPORT_MOUSE_POSITION, None);
-        noecho();
-        if has_colors() {
-            start_color();
-        }
+        self.run_game(&mut ClosureGame::new(init, update, render, exit));
+    }
+
+    /// Run current App against a Game, which keeps its state between frames
+    pub fn run_game<G: Game>(mut self, game: &mut G) {
+        // Initialize current backend and set default values
+        self.backend.init();
 
         // Set cursor mode
         self.set_cursor_mode(DEFAULT_CURSOR_MODE);
@@ -442,30 +891,83 @@ impl App {
         self.set_foreground_color(self.foreground_color);
 
         // User-defined initialization
-        init(&mut self);
+        game.init(&mut self);
+        self.apply_scene_commands();
+        let mut last_size = self.get_size();
 
         loop {
-            // Query for input from App
-            self.input.query(self.window.getch());
+            self.time.tick();
+
+            // Query for input from App, draining every event pending since the last frame
+            let mut events = Vec::new();
+            while events.len() < MAX_EVENTS_PER_FRAME {
+                match self.backend.poll_input() {
+                    Some(event) => events.push(event),
+                    None => break,
+                }
+            }
+            self.input.query(events, self.time.get_delta());
+            for event in self.input.get_events().to_vec() {
+                // Follow the terminal when it gets resized
+                if let InputEvent::Resize(size) = event {
+                    self.width = size.x;
+                    self.height = size.y;
+                    self.resize_buffers();
+                    last_size = size;
+                    game.on_resize(&mut self, size);
+                }
+                game.on_event(&mut self, event);
+            }
+
+            // Run timers and sequences that are due, then tweens and animations
+            self.update_scheduler();
+            self.update_tweens();
+            self.update_animations();
+
+            // User-defined fixed updates, catching up with the time elapsed
+            while self.time.consume_fixed_step() {
+                game.fixed_update(&mut self);
+                self.run_systems(Stage::FixedUpdate);
+            }
 
             // User-defined update
-            update(&mut self);
+            game.update(&mut self);
+            self.update_scenes();
+            self.run_systems(Stage::Update);
+            self.update_cameras();
+
+            // Let the game know when the App has been resized
+            if self.get_size() != last_size {
+                last_size = self.get_size();
+                game.on_resize(&mut self, last_size);
+            }
 
             // User-defined render then refresh the screen
-            self.window.clear();
+            self.clear();
             self.update_borders_corners();
-            render(&mut self);
+            game.render(&mut self);
+            self.render_scenes();
+            self.run_systems(Stage::Render);
+            self.render_world();
+            self.flush_render_queue();
+            self.present();
 
             if self.do_quit {
                 break;
             }
 
-            // Handle frame rate
-            sleep(Duration::from_secs_f32(self.frame_time));
+            // Handle frame rate, only sleeping for what is left of the frame
+            let frame_time = Duration::from_secs_f32(self.frame_time);
+            let spent = self.time.get_frame_time();
+            if spent < frame_time {
+                sleep(frame_time - spent);
+            }
         }
 
-        // User-defined exit
-        exit(&mut self);
-        endwin();
+        // Exit remaining scenes then user-defined exit
+        self.clear_scenes();
+        self.apply_scene_commands();
+        game.exit(&mut self);
+        self.backend.shutdown();
     }
 }
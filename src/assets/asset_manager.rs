@@ -0,0 +1,258 @@
+use super::{
+    char_map_loader::{read, CharMapAsset, CharMapLoader, SpriteSheet},
+    image::Image,
+    level::Level,
+    load_error::LoadError,
+    palette::Palette,
+};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt, fs,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+/// Something the AssetManager can load from a file
+pub trait Asset: Sized + 'static {
+    /// Load asset from a file, char maps being loaded with the manager's loader
+    fn load(path: &Path, loader: &CharMapLoader) -> Result<Self, LoadError>;
+}
+
+// Extension of color layer files, loaded along char maps and sprite sheets of the same name
+const COLOR_LAYER_EXTENSION: &str = "colors";
+
+impl Asset for CharMapAsset {
+    fn load(path: &Path, loader: &CharMapLoader) -> Result<Self, LoadError> {
+        if Image::is_image_path(path) {
+            return loader.load_image(path);
+        }
+        let color_path = path.with_extension(COLOR_LAYER_EXTENSION);
+        if color_path.is_file() {
+            return loader.load_with_colors(path, color_path);
+        }
+        return loader.load(path);
+    }
+}
+
+impl Asset for SpriteSheet {
+    fn load(path: &Path, loader: &CharMapLoader) -> Result<Self, LoadError> {
+        let color_path = path.with_extension(COLOR_LAYER_EXTENSION);
+        if color_path.is_file() {
+            return loader.load_sheet_with_colors(path, color_path);
+        }
+        return loader.load_sheet(path);
+    }
+}
+
+impl Asset for String {
+    fn load(path: &Path, _loader: &CharMapLoader) -> Result<Self, LoadError> {
+        return read(path);
+    }
+}
+
+impl Asset for Palette {
+    fn load(path: &Path, _loader: &CharMapLoader) -> Result<Self, LoadError> {
+        return Palette::parse(&read(path)?, &path.display().to_string());
+    }
+}
+
+impl Asset for Level {
+    fn load(path: &Path, loader: &CharMapLoader) -> Result<Self, LoadError> {
+        return Level::parse(&read(path)?, loader, &path.display().to_string());
+    }
+}
+
+/// A cheap reference to an asset of an AssetManager, which entities can hold
+pub struct Handle<T> {
+    id: u32,
+    asset_type: PhantomData<fn() -> T>,
+}
+
+impl<T> Handle<T> {
+    /// Get index of the asset among assets of its type
+    pub fn get_id(&self) -> u32 {
+        return self.id;
+    }
+}
+
+impl<T> fmt::Debug for Handle<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "Handle({})", self.id)
+    }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Handle<T> {
+        *self
+    }
+}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Handle<T>) -> bool {
+        return self.id == other.id;
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> Hash for Handle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// Loaded assets of one type, boxed so they never move while pointers to them are in use
+struct AssetStore<T> {
+    assets: Vec<Box<T>>,
+    ids: HashMap<PathBuf, u32>,
+}
+
+/// Loads assets by path once and hands out handles to them, keeping load errors
+pub struct AssetManager {
+    loader: CharMapLoader,
+    stores: HashMap<TypeId, Box<dyn Any>>,
+    errors: Vec<LoadError>,
+}
+
+impl AssetManager {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self {
+            loader: CharMapLoader::new(),
+            stores: HashMap::new(),
+            errors: Vec::new(),
+        };
+    }
+
+    /// Set the loader char maps, sprite sheets and levels are loaded with,
+    /// assets already loaded are kept as they are
+    pub fn set_loader(&mut self, loader: CharMapLoader) {
+        self.loader = loader;
+    }
+
+    /// Get the loader char maps, sprite sheets and levels are loaded with
+    pub fn get_loader(&self) -> &CharMapLoader {
+        return &self.loader;
+    }
+
+    /// Load an asset, or get the handle of the one already loaded from this path
+    ///
+    /// Returns None if the asset could not be loaded, the error being kept in the manager
+    pub fn load<T: Asset>(&mut self, path: impl AsRef<Path>) -> Option<Handle<T>> {
+        let path = path.as_ref();
+        let key = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if let Some(id) = self.get_store::<T>().and_then(|store| store.ids.get(&key)) {
+            return Some(Handle {
+                id: *id,
+                asset_type: PhantomData,
+            });
+        }
+
+        let asset = match T::load(path, &self.loader) {
+            Ok(asset) => asset,
+            Err(error) => {
+                self.errors.push(error);
+                return None;
+            }
+        };
+        let store = self.get_store_mut::<T>();
+        let id = store.assets.len() as u32;
+        store.assets.push(Box::new(asset));
+        store.ids.insert(key, id);
+        return Some(Handle {
+            id,
+            asset_type: PhantomData,
+        });
+    }
+
+    /// Add an asset made in code, e.g. a generated char map, getting a handle to it
+    pub fn add<T: Asset>(&mut self, asset: T) -> Handle<T> {
+        let store = self.get_store_mut::<T>();
+        let id = store.assets.len() as u32;
+        store.assets.push(Box::new(asset));
+        return Handle {
+            id,
+            asset_type: PhantomData,
+        };
+    }
+
+    /// Get a loaded asset
+    pub fn get<T: Asset>(&self, handle: Handle<T>) -> Option<&T> {
+        return self
+            .get_store::<T>()?
+            .assets
+            .get(handle.id as usize)
+            .map(|asset| asset.as_ref());
+    }
+
+    /// Load a char map, see `load`
+    ///
+    /// Images are converted to char maps, a `.colors` file of the same name colors the map
+    pub fn load_char_map(&mut self, path: impl AsRef<Path>) -> Option<Handle<CharMapAsset>> {
+        return self.load(path);
+    }
+
+    /// Load a sprite sheet, see `load`
+    ///
+    /// A `.colors` file of the same name colors the frames
+    pub fn load_sprite_sheet(&mut self, path: impl AsRef<Path>) -> Option<Handle<SpriteSheet>> {
+        return self.load(path);
+    }
+
+    /// Load a text file, see `load`
+    pub fn load_text(&mut self, path: impl AsRef<Path>) -> Option<Handle<String>> {
+        return self.load(path);
+    }
+
+    /// Load a palette, see `load`
+    pub fn load_palette(&mut self, path: impl AsRef<Path>) -> Option<Handle<Palette>> {
+        return self.load(path);
+    }
+
+    /// Load a level, see `load`
+    pub fn load_level(&mut self, path: impl AsRef<Path>) -> Option<Handle<Level>> {
+        return self.load(path);
+    }
+
+    /// Get number of loaded assets of a type
+    pub fn get_count<T: Asset>(&self) -> usize {
+        return self.get_store::<T>().map_or(0, |store| store.assets.len());
+    }
+
+    /// Check if some assets failed to load
+    pub fn has_errors(&self) -> bool {
+        return !self.errors.is_empty();
+    }
+
+    /// Get errors of assets that failed to load
+    pub fn get_errors(&self) -> &[LoadError] {
+        return &self.errors;
+    }
+
+    /// Take errors of assets that failed to load
+    pub fn take_errors(&mut self) -> Vec<LoadError> {
+        return std::mem::take(&mut self.errors);
+    }
+
+    fn get_store<T: Asset>(&self) -> Option<&AssetStore<T>> {
+        return self.stores.get(&TypeId::of::<T>())?.downcast_ref();
+    }
+
+    fn get_store_mut<T: Asset>(&mut self) -> &mut AssetStore<T> {
+        return self
+            .stores
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| {
+                Box::new(AssetStore::<T> {
+                    assets: Vec::new(),
+                    ids: HashMap::new(),
+                })
+            })
+            .downcast_mut()
+            .expect("asset store has the wrong type");
+    }
+}
@@ -0,0 +1,674 @@
+use super::{
+    image::Image, image_converter::ImageConverter, load_error::LoadError, palette::Palette,
+};
+use crate::entities::char_object::{CharMap, CharObject};
+use crate::rendering::cell::Cell;
+use crate::types::color32::Color32;
+use ndarray::Array2;
+use std::{collections::HashMap, fs, path::Path};
+
+// Lines starting with this begin a new frame of a sprite sheet, followed by the frame name
+pub const DEFAULT_FRAME_DELIMITER: &str = "---";
+
+// Color layer key for cells keeping the object or App color
+const UNSET_COLOR_KEYS: [char; 2] = [' ', '.'];
+
+/// A char map loaded from text, with its colors if a color layer was given
+pub struct CharMapAsset {
+    chars: Array2<char>,
+    cells: Option<Array2<Cell>>,
+    transparent_char: Option<char>,
+}
+
+impl CharMapAsset {
+    /// Default constructor, cells having to be the same size as chars
+    pub fn new(
+        chars: Array2<char>,
+        cells: Option<Array2<Cell>>,
+        transparent_char: Option<char>,
+    ) -> Result<Self, LoadError> {
+        if let Some(cells) = &cells {
+            if cells.dim() != chars.dim() {
+                return Err(LoadError::ColorLayerMismatch {
+                    source: "char map".to_string(),
+                    frame: None,
+                    expected: chars.dim(),
+                    found: cells.dim(),
+                });
+            }
+        }
+        return Ok(Self::from_parts(chars, cells, transparent_char));
+    }
+
+    // Build an asset from cells known to be the same size as chars
+    pub(crate) fn from_parts(
+        chars: Array2<char>,
+        cells: Option<Array2<Cell>>,
+        transparent_char: Option<char>,
+    ) -> Self {
+        return Self {
+            chars,
+            cells,
+            transparent_char,
+        };
+    }
+
+    /// Get plain characters of the map
+    pub fn get_chars(&self) -> &Array2<char> {
+        return &self.chars;
+    }
+
+    /// Get colored cells of the map if it was loaded with a color layer
+    pub fn get_cells(&self) -> Option<&Array2<Cell>> {
+        return self.cells.as_ref();
+    }
+
+    /// Get a CharMap pointing to this asset, colored if possible
+    ///
+    /// The asset has to stay at the same place in memory while the CharMap is in use
+    pub fn get_char_map(&self) -> CharMap {
+        return match &self.cells {
+            Some(cells) => CharMap::Cells(cells),
+            None => CharMap::Chars(&self.chars),
+        };
+    }
+
+    /// Get the character left undrawn
+    pub fn get_transparent_char(&self) -> Option<char> {
+        return self.transparent_char;
+    }
+
+    /// Get map width
+    pub fn get_width(&self) -> usize {
+        return self.chars.ncols();
+    }
+
+    /// Get map height
+    pub fn get_height(&self) -> usize {
+        return self.chars.nrows();
+    }
+
+    /// Get a CharObject drawing this asset, which has to outlive the object, see
+    /// `CharObject::from_asset` for assets held by an AssetManager
+    pub fn to_char_object(&self) -> CharObject {
+        let mut char_object = CharObject::from_char_map(self.get_char_map());
+        char_object.set_transparent_char(self.transparent_char);
+        return char_object;
+    }
+}
+
+/// Named char maps loaded from a single text file, in file order
+pub struct SpriteSheet {
+    names: Vec<String>,
+    frames: HashMap<String, CharMapAsset>,
+}
+
+impl SpriteSheet {
+    /// Get a frame by name
+    pub fn get_frame(&self, name: &str) -> Option<&CharMapAsset> {
+        return self.frames.get(name);
+    }
+
+    /// Get frame names in file order
+    pub fn get_names(&self) -> &[String] {
+        return &self.names;
+    }
+
+    /// Get frames in file order
+    pub fn get_frames(&self) -> Vec<&CharMapAsset> {
+        return self.names.iter().map(|name| &self.frames[name]).collect();
+    }
+
+    /// Get number of frames
+    pub fn len(&self) -> usize {
+        return self.names.len();
+    }
+
+    /// Check if the sheet has no frame
+    pub fn is_empty(&self) -> bool {
+        return self.names.is_empty();
+    }
+}
+
+/// Lines of a sprite sheet frame
+struct FrameSection<'a> {
+    name: String,
+    // Line number of the first line in the file
+    first_line: usize,
+    lines: Vec<&'a str>,
+}
+
+/// Loads char maps and sprite sheets from plain text files
+///
+/// Every line of a map has to be as wide as the first one. A color layer is a second
+/// file of the same size, each character being a palette key for the foreground color
+/// of the cell at the same place, spaces and dots keeping the object or App color.
+/// Frames of a sprite sheet start with a delimiter line holding the frame name, e.g. `--- idle`
+pub struct CharMapLoader {
+    transparent_char: Option<char>,
+    image_converter: ImageConverter,
+    frame_delimiter: String,
+    palette: HashMap<char, Color32>,
+}
+
+impl CharMapLoader {
+    /// Default constructor, with no transparent character and the default palette
+    pub fn new() -> Self {
+        let palette = [
+            ('k', Color32::black()),
+            ('r', Color32::red()),
+            ('g', Color32::green()),
+            ('y', Color32::yellow()),
+            ('b', Color32::blue()),
+            ('m', Color32::pink()),
+            ('c', Color32::teal()),
+            ('w', Color32::white()),
+        ];
+        return Self {
+            transparent_char: None,
+            image_converter: ImageConverter::new(),
+            frame_delimiter: DEFAULT_FRAME_DELIMITER.to_string(),
+            palette: palette.iter().copied().collect(),
+        };
+    }
+
+    /// Set the character left undrawn so what is underneath shows through
+    pub fn set_transparent_char(mut self, transparent_char: char) -> CharMapLoader {
+        self.transparent_char = Some(transparent_char);
+        return self;
+    }
+
+    /// Set what lines starting a new frame of a sprite sheet start with
+    pub fn set_frame_delimiter(mut self, frame_delimiter: &str) -> CharMapLoader {
+        self.frame_delimiter = frame_delimiter.to_string();
+        return self;
+    }
+
+    /// Set the color of a color layer key
+    pub fn set_palette_color(mut self, key: char, color: Color32) -> CharMapLoader {
+        self.palette.insert(key, color);
+        return self;
+    }
+
+    /// Set the colors of every key of a palette, keeping other keys
+    pub fn set_palette(mut self, palette: &Palette) -> CharMapLoader {
+        self.palette.extend(palette.get_colors());
+        return self;
+    }
+
+    /// Get the character left undrawn
+    pub fn get_transparent_char(&self) -> Option<char> {
+        return self.transparent_char;
+    }
+
+    /// Set how images are turned into char maps
+    pub fn set_image_converter(mut self, image_converter: ImageConverter) -> CharMapLoader {
+        self.image_converter = image_converter;
+        return self;
+    }
+
+    /// Get what lines starting a new frame of a sprite sheet start with
+    pub fn get_frame_delimiter(&self) -> &str {
+        return &self.frame_delimiter;
+    }
+
+    /// Load a char map from a text file
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<CharMapAsset, LoadError> {
+        let source = path.as_ref().display().to_string();
+        return self.parse(&read(path)?, &source);
+    }
+
+    /// Load a char map from an image file, see `Image::load` for supported formats
+    ///
+    /// Transparent pixels get the loader's transparent character if it has one
+    pub fn load_image(&self, path: impl AsRef<Path>) -> Result<CharMapAsset, LoadError> {
+        let mut converter = self.image_converter.clone();
+        if self.transparent_char.is_some() {
+            converter = converter.set_transparent_char(self.transparent_char);
+        }
+        return Ok(converter.convert(&Image::load(path)?));
+    }
+
+    /// Load a char map from a text file and its colors from a color layer file
+    pub fn load_with_colors(
+        &self,
+        path: impl AsRef<Path>,
+        color_path: impl AsRef<Path>,
+    ) -> Result<CharMapAsset, LoadError> {
+        let source = path.as_ref().display().to_string();
+        let color_source = color_path.as_ref().display().to_string();
+        return self.parse_with_colors(&read(path)?, &read(color_path)?, &source, &color_source);
+    }
+
+    /// Load every frame of a sprite sheet from a text file
+    pub fn load_sheet(&self, path: impl AsRef<Path>) -> Result<SpriteSheet, LoadError> {
+        let source = path.as_ref().display().to_string();
+        return self.parse_sheet(&read(path)?, &source);
+    }
+
+    /// Load every frame of a sprite sheet, with colors from a color layer sheet
+    /// having frames of the same names
+    pub fn load_sheet_with_colors(
+        &self,
+        path: impl AsRef<Path>,
+        color_path: impl AsRef<Path>,
+    ) -> Result<SpriteSheet, LoadError> {
+        let source = path.as_ref().display().to_string();
+        let color_source = color_path.as_ref().display().to_string();
+        let colors = read(color_path)?;
+        return self.parse_sheet_with_colors(&read(path)?, &colors, &source, &color_source);
+    }
+
+    /// Parse a char map, source naming the text in errors
+    pub fn parse(&self, text: &str, source: &str) -> Result<CharMapAsset, LoadError> {
+        return self.parse_from_line(text, 1, source);
+    }
+
+    /// Parse a char map starting at a line of a bigger text, for line numbers in errors
+    pub(crate) fn parse_from_line(
+        &self,
+        text: &str,
+        first_line: usize,
+        source: &str,
+    ) -> Result<CharMapAsset, LoadError> {
+        let lines: Vec<&str> = text.lines().collect();
+        return Ok(CharMapAsset {
+            chars: to_char_array(&lines, first_line, source)?,
+            cells: None,
+            transparent_char: self.transparent_char,
+        });
+    }
+
+    /// Parse a char map and its color layer, color source naming the color layer in errors
+    pub fn parse_with_colors(
+        &self,
+        text: &str,
+        colors: &str,
+        source: &str,
+        color_source: &str,
+    ) -> Result<CharMapAsset, LoadError> {
+        let asset = self.parse(text, source)?;
+        let lines: Vec<&str> = colors.lines().collect();
+        return self.add_color_layer(asset, &lines, 1, color_source, None);
+    }
+
+    /// Parse a sprite sheet, source naming the text in errors
+    pub fn parse_sheet(&self, text: &str, source: &str) -> Result<SpriteSheet, LoadError> {
+        return self.parse_sheet_from(text, None, source);
+    }
+
+    /// Parse a sprite sheet and its color layer sheet, each frame being colored by the
+    /// color layer frame of the same name
+    pub fn parse_sheet_with_colors(
+        &self,
+        text: &str,
+        colors: &str,
+        source: &str,
+        color_source: &str,
+    ) -> Result<SpriteSheet, LoadError> {
+        return self.parse_sheet_from(text, Some((colors, color_source)), source);
+    }
+
+    // Parse a sprite sheet, with an optional color layer sheet and its source
+    fn parse_sheet_from(
+        &self,
+        text: &str,
+        colors: Option<(&str, &str)>,
+        source: &str,
+    ) -> Result<SpriteSheet, LoadError> {
+        let sections = self.split_frames(text, source)?;
+        let mut color_sections = match colors {
+            Some((colors, color_source)) => {
+                let mut color_sections = HashMap::new();
+                for section in self.split_frames(colors, color_source)? {
+                    if color_sections.contains_key(&section.name) {
+                        return Err(LoadError::DuplicateFrame {
+                            source: color_source.to_string(),
+                            name: section.name,
+                        });
+                    }
+                    color_sections.insert(section.name.clone(), section);
+                }
+                Some((color_sections, color_source))
+            }
+            None => None,
+        };
+
+        let mut sheet = SpriteSheet {
+            names: Vec::new(),
+            frames: HashMap::new(),
+        };
+        for section in sections.iter() {
+            let name = &section.name;
+            let mut asset = CharMapAsset {
+                chars: to_char_array(&section.lines, section.first_line, source)?,
+                cells: None,
+                transparent_char: self.transparent_char,
+            };
+            if let Some((color_sections, color_source)) = &mut color_sections {
+                let color_section = match color_sections.remove(name) {
+                    Some(color_section) => color_section,
+                    None => {
+                        return Err(LoadError::MissingFrame {
+                            source: color_source.to_string(),
+                            name: name.clone(),
+                        })
+                    }
+                };
+                asset = self.add_color_layer(
+                    asset,
+                    &color_section.lines,
+                    color_section.first_line,
+                    color_source,
+                    Some(name),
+                )?;
+            }
+            if sheet.frames.contains_key(name) {
+                return Err(LoadError::DuplicateFrame {
+                    source: source.to_string(),
+                    name: name.clone(),
+                });
+            }
+            sheet.names.push(name.clone());
+            sheet.frames.insert(name.clone(), asset);
+        }
+
+        // Color layer frames have to match the sheet frames one for one
+        if let Some((color_sections, _)) = color_sections {
+            if let Some(section) = color_sections
+                .values()
+                .min_by_key(|section| section.first_line)
+            {
+                return Err(LoadError::MissingFrame {
+                    source: source.to_string(),
+                    name: section.name.clone(),
+                });
+            }
+        }
+        if sheet.is_empty() {
+            return Err(LoadError::Empty {
+                source: source.to_string(),
+            });
+        }
+        return Ok(sheet);
+    }
+
+    /// Split a sprite sheet into frame names, first line numbers and lines,
+    /// unnamed frames being named after their index
+    fn split_frames<'a>(
+        &self,
+        text: &'a str,
+        source: &str,
+    ) -> Result<Vec<FrameSection<'a>>, LoadError> {
+        let mut sections: Vec<FrameSection> = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            if let Some(name) = line.strip_prefix(self.frame_delimiter.as_str()) {
+                let name = match name.trim() {
+                    "" => sections.len().to_string(),
+                    name => name.to_string(),
+                };
+                sections.push(FrameSection {
+                    name,
+                    first_line: index + 2,
+                    lines: Vec::new(),
+                });
+                continue;
+            }
+            match sections.last_mut() {
+                Some(section) => section.lines.push(line),
+                // Blank lines before the first delimiter are ignored
+                None if line.trim().is_empty() => {}
+                None => sections.push(FrameSection {
+                    name: "0".to_string(),
+                    first_line: index + 1,
+                    lines: vec![line],
+                }),
+            }
+        }
+        if sections.is_empty() {
+            return Err(LoadError::Empty {
+                source: source.to_string(),
+            });
+        }
+        return Ok(sections);
+    }
+
+    /// Color a char map with the lines of a color layer of the same size, starting at
+    /// a line of the color layer source, frame naming the sprite sheet frame in errors
+    fn add_color_layer(
+        &self,
+        mut asset: CharMapAsset,
+        lines: &[&str],
+        first_line: usize,
+        source: &str,
+        frame: Option<&str>,
+    ) -> Result<CharMapAsset, LoadError> {
+        let keys = to_char_array(lines, first_line, source)?;
+        if keys.dim() != asset.chars.dim() {
+            return Err(LoadError::ColorLayerMismatch {
+                source: source.to_string(),
+                frame: frame.map(str::to_string),
+                expected: asset.chars.dim(),
+                found: keys.dim(),
+            });
+        }
+
+        let mut cells = Array2::from_elem(asset.chars.dim(), Cell::blank());
+        for ((y, x), glyph) in asset.chars.indexed_iter() {
+            let key = keys[[y, x]];
+            let mut cell = Cell::new(*glyph);
+            if !UNSET_COLOR_KEYS.contains(&key) {
+                cell.foreground = match self.palette.get(&key) {
+                    Some(color) => *color,
+                    None => {
+                        return Err(LoadError::UnknownColor {
+                            source: source.to_string(),
+                            line: first_line + y,
+                            column: x + 1,
+                            key,
+                        })
+                    }
+                };
+            }
+            cells[[y, x]] = cell;
+        }
+        asset.cells = Some(cells);
+        return Ok(asset);
+    }
+}
+
+/// Read a whole text file
+pub(crate) fn read(path: impl AsRef<Path>) -> Result<String, LoadError> {
+    return fs::read_to_string(path.as_ref()).map_err(|error| LoadError::Io {
+        source: path.as_ref().display().to_string(),
+        error,
+    });
+}
+
+/// Turn lines into a 2D array, trailing blank lines being ignored
+fn to_char_array(
+    lines: &[&str],
+    first_line: usize,
+    source: &str,
+) -> Result<Array2<char>, LoadError> {
+    let mut rows: Vec<Vec<char>> = lines
+        .iter()
+        .map(|line| line.trim_end_matches('\r').chars().collect())
+        .collect();
+    while rows.last().is_some_and(|row| row.is_empty()) {
+        rows.pop();
+    }
+    if rows.is_empty() || rows[0].is_empty() {
+        return Err(LoadError::Empty {
+            source: source.to_string(),
+        });
+    }
+
+    let width = rows[0].len();
+    for (index, row) in rows.iter().enumerate() {
+        if row.len() != width {
+            return Err(LoadError::Ragged {
+                source: source.to_string(),
+                line: first_line + index,
+                expected: width,
+                found: row.len(),
+            });
+        }
+    }
+
+    let height = rows.len();
+    let chars: Vec<char> = rows.into_iter().flatten().collect();
+    return Ok(Array2::from_shape_vec((height, width), chars).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    // Get the message of an error, failing if there is none
+    fn error<T>(result: Result<T, LoadError>) -> String {
+        return match result {
+            Ok(_) => panic!("expected a load error"),
+            Err(error) => error.to_string(),
+        };
+    }
+
+    const SHEET: &str = "--- left\n<#\n<#\n--- right\n#>\n#>\n";
+
+    #[test]
+    fn parses_char_maps() {
+        let loader = CharMapLoader::new().set_transparent_char(' ');
+        let asset = loader.parse("ab\ncd\n\n", "map.txt").unwrap();
+        assert_eq!(asset.get_chars(), &array![['a', 'b'], ['c', 'd']]);
+        assert_eq!(asset.get_transparent_char(), Some(' '));
+        assert!(asset.get_cells().is_none());
+
+        assert_eq!(
+            error(loader.parse("ab\nc\n", "map.txt")),
+            "map.txt:2: line is 1 characters wide, expected 2"
+        );
+        assert_eq!(
+            error(loader.parse("\n\n", "map.txt")),
+            "map.txt: nothing to load"
+        );
+    }
+
+    #[test]
+    fn parses_sheet_frames_in_order() {
+        let sheet = CharMapLoader::new()
+            .parse_sheet(SHEET, "sheet.txt")
+            .unwrap();
+        assert_eq!(
+            sheet.get_names(),
+            &["left".to_string(), "right".to_string()]
+        );
+        assert_eq!(
+            sheet.get_frame("right").unwrap().get_chars(),
+            &array![['#', '>'], ['#', '>']]
+        );
+        assert!(sheet.get_frame("up").is_none());
+
+        // Unnamed frames are named after their index
+        let sheet = CharMapLoader::new()
+            .parse_sheet("a\n---\nb\n", "sheet.txt")
+            .unwrap();
+        assert_eq!(sheet.get_names(), &["0".to_string(), "1".to_string()]);
+    }
+
+    #[test]
+    fn reports_sheet_errors_at_sheet_lines() {
+        let loader = CharMapLoader::new();
+        assert_eq!(
+            error(loader.parse_sheet("--- a\nxx\n--- b\nxx\nx\n", "sheet.txt")),
+            "sheet.txt:5: line is 1 characters wide, expected 2"
+        );
+        assert!(matches!(
+            loader.parse_sheet("--- a\nx\n--- a\ny\n", "sheet.txt"),
+            Err(LoadError::DuplicateFrame { .. })
+        ));
+    }
+
+    #[test]
+    fn colors_maps_with_palette_keys() {
+        let loader = CharMapLoader::new().set_palette_color('o', Color32::from_hex("ff8000"));
+        let asset = loader
+            .parse_with_colors("ab\ncd", "r.\n o", "map.txt", "map.colors")
+            .unwrap();
+        let cells = asset.get_cells().unwrap();
+        assert_eq!(cells[[0, 0]].glyph, 'a');
+        assert_eq!(cells[[0, 0]].foreground, Color32::red());
+        assert_eq!(cells[[1, 1]].foreground, Color32::from_hex("ff8000"));
+        // Unset keys keep the object colors
+        assert_eq!(cells[[0, 1]].foreground, Cell::new('b').foreground);
+
+        assert_eq!(
+            error(loader.parse_with_colors("ab\ncd", "rr\nrZ", "map.txt", "map.colors")),
+            "map.colors:2:2: unknown color key 'Z'"
+        );
+        assert_eq!(
+            error(loader.parse_with_colors("ab\ncd", "rr", "map.txt", "map.colors")),
+            "map.colors: color layer is 2x1, expected 2x2"
+        );
+    }
+
+    #[test]
+    fn matches_color_frames_by_name() {
+        // Color frames are given in another order than the sheet's
+        let colors = "--- right\ngr\ngr\n--- left\nrg\nrg\n";
+        let sheet = CharMapLoader::new()
+            .parse_sheet_with_colors(SHEET, colors, "sheet.txt", "sheet.colors")
+            .unwrap();
+        let left = sheet.get_frame("left").unwrap().get_cells().unwrap();
+        assert_eq!(left[[0, 0]].glyph, '<');
+        assert_eq!(left[[0, 0]].foreground, Color32::red());
+        let right = sheet.get_frame("right").unwrap().get_cells().unwrap();
+        assert_eq!(right[[1, 0]].foreground, Color32::green());
+    }
+
+    #[test]
+    fn reports_color_errors_at_color_lines() {
+        let loader = CharMapLoader::new();
+        let colors = "--- left\nrr\nrr\n--- right\nrr\nZr\n";
+        assert_eq!(
+            error(loader.parse_sheet_with_colors(SHEET, colors, "sheet.txt", "sheet.colors")),
+            "sheet.colors:6:1: unknown color key 'Z'"
+        );
+
+        let colors = "--- left\nrr\nrr\n";
+        assert_eq!(
+            error(loader.parse_sheet_with_colors(SHEET, colors, "sheet.txt", "sheet.colors")),
+            "sheet.colors: missing frame 'right'"
+        );
+
+        let colors = "--- left\nrr\nrr\n--- up\nr\n--- right\nrr\nrr\n";
+        assert_eq!(
+            error(loader.parse_sheet_with_colors(SHEET, colors, "sheet.txt", "sheet.colors")),
+            "sheet.txt: missing frame 'up'"
+        );
+
+        let colors = "--- left\nrr\nrr\n--- right\nrrr\nrrr\n";
+        assert_eq!(
+            error(loader.parse_sheet_with_colors(SHEET, colors, "sheet.txt", "sheet.colors")),
+            "sheet.colors: color layer of frame 'right' is 3x2, expected 2x2"
+        );
+
+        let colors = "--- left\nrr\nrr\n--- left\nrr\nrr\n";
+        assert!(matches!(
+            loader.parse_sheet_with_colors(SHEET, colors, "sheet.txt", "sheet.colors"),
+            Err(LoadError::DuplicateFrame { source, .. }) if source == "sheet.colors"
+        ));
+    }
+
+    #[test]
+    fn checks_cells_match_chars() {
+        let chars = array![['a', 'b']];
+        let cells = Some(array![[Cell::new('a')]]);
+        assert_eq!(
+            error(CharMapAsset::new(chars.clone(), cells, None)),
+            "char map: color layer is 1x1, expected 2x1"
+        );
+        let cells = Some(array![[Cell::new('a'), Cell::new('b')]]);
+        assert!(CharMapAsset::new(chars, cells, None).is_ok());
+    }
+}
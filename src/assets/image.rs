@@ -0,0 +1,435 @@
+use super::load_error::LoadError;
+use crate::types::color32::Color32;
+use std::{fs, path::Path};
+
+/// A bitmap image, pixels stored row by row
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color32>,
+    is_grayscale: bool,
+}
+
+impl Image {
+    /// Default constructor, pixels have to be width * height long
+    pub fn new(
+        width: usize,
+        height: usize,
+        pixels: Vec<Color32>,
+        is_grayscale: bool,
+    ) -> Result<Image, LoadError> {
+        if width.checked_mul(height) != Some(pixels.len()) {
+            return Err(LoadError::PixelCount {
+                width,
+                height,
+                found: pixels.len(),
+            });
+        }
+        return Ok(Self {
+            width,
+            height,
+            pixels,
+            is_grayscale,
+        });
+    }
+
+    /// Check if a path has the extension of an image format that can be loaded
+    pub fn is_image_path(path: &Path) -> bool {
+        let extension = match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) => extension.to_ascii_lowercase(),
+            None => return false,
+        };
+        let is_pnm = matches!(extension.as_str(), "pbm" | "pgm" | "ppm" | "pnm");
+        return is_pnm || cfg!(feature = "png") && extension == "png";
+    }
+
+    /// Load an image from a PBM, PGM or PPM file, or a PNG file with the `png` feature
+    pub fn load(path: impl AsRef<Path>) -> Result<Image, LoadError> {
+        let path = path.as_ref();
+        let source = path.display().to_string();
+        let bytes = fs::read(path).map_err(|error| LoadError::Io {
+            source: source.clone(),
+            error,
+        })?;
+        #[cfg(feature = "png")]
+        {
+            if bytes.starts_with(b"\x89PNG") {
+                return Image::parse_png(&bytes, &source);
+            }
+        }
+        return Image::parse_pnm(&bytes, &source);
+    }
+
+    /// Parse a PBM, PGM or PPM image, in plain or raw format
+    pub fn parse_pnm(bytes: &[u8], source: &str) -> Result<Image, LoadError> {
+        let mut reader = PnmReader {
+            bytes,
+            position: 0,
+            source,
+        };
+        let magic = reader.read_token()?;
+        let (is_plain, channels) = match magic.as_str() {
+            "P1" => (true, 0),
+            "P2" => (true, 1),
+            "P3" => (true, 3),
+            "P4" => (false, 0),
+            "P5" => (false, 1),
+            "P6" => (false, 3),
+            _ => return Err(reader.error("not a PBM, PGM or PPM image")),
+        };
+        let width = reader.read_number()? as usize;
+        let height = reader.read_number()? as usize;
+        let max_value = if channels == 0 {
+            1
+        } else {
+            reader.read_number()?
+        };
+        if width == 0 || height == 0 {
+            return Err(LoadError::Empty {
+                source: source.to_string(),
+            });
+        }
+        if max_value == 0 || max_value > u16::MAX as u32 {
+            return Err(reader.error("maximum value has to be between 1 and 65535"));
+        }
+        // Raw data starts after a single whitespace
+        if !is_plain {
+            reader.position += 1;
+        }
+
+        // Check the data can hold every sample before allocating anything, plain samples
+        // taking at least a byte each
+        let too_large = || reader.error("image is larger than its data");
+        let pixel_count = width.checked_mul(height).ok_or_else(too_large)?;
+        let sample_size = if max_value > 255 { 2 } else { 1 };
+        let data_size = if channels == 0 && !is_plain {
+            width.div_ceil(8).checked_mul(height)
+        } else if is_plain {
+            pixel_count.checked_mul(channels.max(1))
+        } else {
+            pixel_count
+                .checked_mul(channels)
+                .and_then(|count| count.checked_mul(sample_size))
+        };
+        let remaining = reader.bytes.len().saturating_sub(reader.position);
+        if data_size.is_none_or(|size| size > remaining) {
+            return Err(too_large());
+        }
+
+        let mut pixels = Vec::with_capacity(pixel_count);
+        if channels == 0 {
+            // Bitmaps: 1 is black, raw rows are packed 8 pixels a byte
+            for y in 0..height {
+                for x in 0..width {
+                    let bit = if is_plain {
+                        reader.read_bit()?
+                    } else {
+                        let byte = reader.read_byte(y * width.div_ceil(8) + x / 8)?;
+                        (byte >> (7 - x % 8)) & 1
+                    };
+                    pixels.push(if bit == 1 {
+                        Color32::black()
+                    } else {
+                        Color32::white()
+                    });
+                }
+            }
+        } else {
+            // Raw samples take 2 bytes when the maximum value does not fit in one
+            let mut offset = 0;
+            let mut samples = Vec::with_capacity(pixel_count * channels);
+            for _ in 0..pixel_count * channels {
+                let value = if is_plain {
+                    reader.read_number()?
+                } else if sample_size == 2 {
+                    (reader.read_byte(offset)? as u32) << 8 | reader.read_byte(offset + 1)? as u32
+                } else {
+                    reader.read_byte(offset)? as u32
+                };
+                offset += sample_size;
+                samples.push(value.min(max_value) * 255 / max_value);
+            }
+            for sample in samples.chunks(channels) {
+                let (r, g, b) = if channels == 1 {
+                    (sample[0], sample[0], sample[0])
+                } else {
+                    (sample[0], sample[1], sample[2])
+                };
+                pixels.push(Color32 { r, g, b, a: 255 });
+            }
+        }
+        return Image::new(width, height, pixels, channels != 3);
+    }
+
+    /// Parse a PNG image
+    #[cfg(feature = "png")]
+    pub fn parse_png(bytes: &[u8], source: &str) -> Result<Image, LoadError> {
+        let invalid = |reason: String| LoadError::InvalidFormat {
+            source: source.to_string(),
+            line: 1,
+            reason,
+        };
+        let mut decoder = png::Decoder::new(bytes);
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder
+            .read_info()
+            .map_err(|error| invalid(error.to_string()))?;
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader
+            .next_frame(&mut data)
+            .map_err(|error| invalid(error.to_string()))?;
+
+        let (width, height) = (info.width as usize, info.height as usize);
+        let channels = info.color_type.samples();
+        let is_grayscale = matches!(
+            info.color_type,
+            png::ColorType::Grayscale | png::ColorType::GrayscaleAlpha
+        );
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            let row = &data[y * info.line_size..];
+            for x in 0..width {
+                let pixel = &row[x * channels..(x + 1) * channels];
+                let (r, g, b, a) = match channels {
+                    1 => (pixel[0], pixel[0], pixel[0], 255),
+                    2 => (pixel[0], pixel[0], pixel[0], pixel[1]),
+                    3 => (pixel[0], pixel[1], pixel[2], 255),
+                    _ => (pixel[0], pixel[1], pixel[2], pixel[3]),
+                };
+                pixels.push(Color32 {
+                    r: r as u32,
+                    g: g as u32,
+                    b: b as u32,
+                    a: a as u32,
+                });
+            }
+        }
+        return Image::new(width, height, pixels, is_grayscale);
+    }
+
+    /// Get image width in pixels
+    pub fn get_width(&self) -> usize {
+        return self.width;
+    }
+
+    /// Get image height in pixels
+    pub fn get_height(&self) -> usize {
+        return self.height;
+    }
+
+    /// Get a pixel
+    pub fn get_pixel(&self, x: usize, y: usize) -> Option<Color32> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        return Some(self.pixels[y * self.width + x]);
+    }
+
+    /// Check if the image has no color, only shades of gray
+    pub fn is_grayscale(&self) -> bool {
+        return self.is_grayscale;
+    }
+}
+
+/// Reads the header and samples of a PNM image
+struct PnmReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    source: &'a str,
+}
+
+impl PnmReader<'_> {
+    /// Get an error at the current line
+    fn error(&self, reason: &str) -> LoadError {
+        let end = self.position.min(self.bytes.len());
+        let line = self.bytes[..end]
+            .iter()
+            .filter(|byte| **byte == b'\n')
+            .count()
+            + 1;
+        return LoadError::InvalidFormat {
+            source: self.source.to_string(),
+            line,
+            reason: reason.to_string(),
+        };
+    }
+
+    /// Skip whitespace and comments
+    fn skip_whitespace(&mut self) {
+        while let Some(byte) = self.bytes.get(self.position) {
+            if *byte == b'#' {
+                while self
+                    .bytes
+                    .get(self.position)
+                    .is_some_and(|byte| *byte != b'\n')
+                {
+                    self.position += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Read a whitespace separated token
+    fn read_token(&mut self) -> Result<String, LoadError> {
+        self.skip_whitespace();
+        let start = self.position;
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(|byte| !byte.is_ascii_whitespace() && *byte != b'#')
+        {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(self.error("unexpected end of data"));
+        }
+        return Ok(String::from_utf8_lossy(&self.bytes[start..self.position]).to_string());
+    }
+
+    /// Read a decimal number
+    fn read_number(&mut self) -> Result<u32, LoadError> {
+        let token = self.read_token()?;
+        return token.parse().map_err(|_| self.error("expected a number"));
+    }
+
+    /// Read a single 0 or 1 digit of a plain bitmap, which may not be separated
+    fn read_bit(&mut self) -> Result<u8, LoadError> {
+        self.skip_whitespace();
+        return match self.bytes.get(self.position) {
+            Some(b'0') => {
+                self.position += 1;
+                Ok(0)
+            }
+            Some(b'1') => {
+                self.position += 1;
+                Ok(1)
+            }
+            Some(_) => Err(self.error("expected 0 or 1")),
+            None => Err(self.error("unexpected end of data")),
+        };
+    }
+
+    /// Read a byte of raw data, at an offset from the current position
+    fn read_byte(&self, offset: usize) -> Result<u8, LoadError> {
+        return match self.bytes.get(self.position + offset) {
+            Some(byte) => Ok(*byte),
+            None => Err(self.error("unexpected end of data")),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(bytes: &[u8]) -> Result<Image, LoadError> {
+        return Image::parse_pnm(bytes, "test.pnm");
+    }
+
+    fn gray(value: u32) -> Color32 {
+        return Color32 {
+            r: value,
+            g: value,
+            b: value,
+            a: 255,
+        };
+    }
+
+    #[test]
+    fn parses_plain_bitmap() {
+        let image = parse(b"P1\n# a comment\n3 2\n1 0 1\n010").unwrap();
+        assert_eq!((image.get_width(), image.get_height()), (3, 2));
+        assert!(image.is_grayscale());
+        assert_eq!(image.get_pixel(0, 0), Some(Color32::black()));
+        assert_eq!(image.get_pixel(1, 0), Some(Color32::white()));
+        assert_eq!(image.get_pixel(1, 1), Some(Color32::black()));
+        assert!(image.get_pixel(3, 0).is_none());
+    }
+
+    #[test]
+    fn parses_plain_graymap_and_pixmap() {
+        let image = parse(b"P2 2 1 4 0 4").unwrap();
+        assert_eq!(image.get_pixel(0, 0), Some(gray(0)));
+        assert_eq!(image.get_pixel(1, 0), Some(gray(255)));
+
+        let image = parse(b"P3 1 1 255 255 128 0").unwrap();
+        assert!(!image.is_grayscale());
+        assert_eq!(image.get_pixel(0, 0), Some(Color32::from_hex("ff8000")));
+    }
+
+    #[test]
+    fn parses_raw_formats() {
+        let image = parse(b"P5 2 1 255\n\x00\x80").unwrap();
+        assert_eq!(image.get_pixel(1, 0), Some(gray(0x80)));
+
+        // Two bytes a sample above 255
+        let image = parse(b"P5 1 1 65535\n\xff\xff").unwrap();
+        assert_eq!(image.get_pixel(0, 0), Some(gray(255)));
+
+        let image = parse(b"P6 1 1 255\n\x10\x20\x30").unwrap();
+        assert_eq!(image.get_pixel(0, 0), Some(Color32::from_hex("102030")));
+
+        // Rows of packed bits start on a new byte
+        let image = parse(b"P4 9 1\n\x80\x80").unwrap();
+        assert_eq!(image.get_pixel(0, 0), Some(Color32::black()));
+        assert_eq!(image.get_pixel(1, 0), Some(Color32::white()));
+        assert_eq!(image.get_pixel(8, 0), Some(Color32::black()));
+    }
+
+    #[test]
+    fn rejects_invalid_headers() {
+        assert!(matches!(
+            parse(b"P7 1 1 255"),
+            Err(LoadError::InvalidFormat { line: 1, .. })
+        ));
+        assert!(matches!(
+            parse(b"P2\n1 x"),
+            Err(LoadError::InvalidFormat { line: 2, .. })
+        ));
+        assert!(matches!(parse(b"P2 0 1 255"), Err(LoadError::Empty { .. })));
+        assert!(matches!(
+            parse(b"P2 1 1 0 0"),
+            Err(LoadError::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_images_larger_than_their_data() {
+        let huge = format!("P6 {} {} 255\n\x00", u32::MAX, u32::MAX);
+        assert!(matches!(
+            parse(huge.as_bytes()),
+            Err(LoadError::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            parse(b"P5 4 4 255\n\x00\x00"),
+            Err(LoadError::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            parse(b"P3 2 1 255 1 2 3"),
+            Err(LoadError::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            parse(b"P1 3 1 1 1"),
+            Err(LoadError::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn checks_pixel_count() {
+        let result = Image::new(2, 2, vec![Color32::black(); 3], true);
+        assert!(matches!(
+            result,
+            Err(LoadError::PixelCount {
+                width: 2,
+                height: 2,
+                found: 3
+            })
+        ));
+        assert!(Image::new(usize::MAX, 2, Vec::new(), true).is_err());
+        assert!(Image::new(1, 3, vec![Color32::black(); 3], true).is_ok());
+    }
+}
@@ -0,0 +1,151 @@
+use super::{char_map_loader::CharMapAsset, image::Image};
+use crate::rendering::cell::Cell;
+use crate::types::color32::Color32;
+use ndarray::Array2;
+
+// Characters from darkest to brightest, for light text on a dark terminal
+pub const DEFAULT_RAMP: &str = " .:-=+*#%@";
+
+// Terminal cells are about twice as tall as they are wide
+pub const DEFAULT_CELL_ASPECT: f32 = 2.0;
+
+#[derive(Clone)]
+/// Turns images into char maps, each cell showing the average brightness of the
+/// pixels it covers as a character of a ramp, and their average color if enabled
+pub struct ImageConverter {
+    ramp: Vec<char>,
+    width: Option<usize>,
+    cell_aspect: f32,
+    is_inverted: bool,
+    is_colored: bool,
+    transparent_char: Option<char>,
+}
+
+impl ImageConverter {
+    /// Default constructor, one cell per pixel column with colors
+    pub fn new() -> Self {
+        return Self {
+            ramp: DEFAULT_RAMP.chars().collect(),
+            width: None,
+            cell_aspect: DEFAULT_CELL_ASPECT,
+            is_inverted: false,
+            is_colored: true,
+            transparent_char: None,
+        };
+    }
+
+    /// Set characters from darkest to brightest, keeping the current ramp if empty
+    pub fn set_ramp(mut self, ramp: &str) -> ImageConverter {
+        if !ramp.is_empty() {
+            self.ramp = ramp.chars().collect();
+        }
+        return self;
+    }
+
+    /// Set width of the char map in cells, the height following the image's proportions
+    pub fn set_width(mut self, width: usize) -> ImageConverter {
+        self.width = Some(width.max(1));
+        return self;
+    }
+
+    /// Set how many times taller than wide a cell is
+    pub fn set_cell_aspect(mut self, cell_aspect: f32) -> ImageConverter {
+        self.cell_aspect = cell_aspect.max(0.1);
+        return self;
+    }
+
+    /// Set whether bright pixels get dark characters, for dark text on a light terminal
+    pub fn set_inverted(mut self, value: bool) -> ImageConverter {
+        self.is_inverted = value;
+        return self;
+    }
+
+    /// Set whether cells of color images get the color of their pixels
+    pub fn set_colored(mut self, value: bool) -> ImageConverter {
+        self.is_colored = value;
+        return self;
+    }
+
+    /// Set the character given to transparent pixels and left undrawn
+    pub fn set_transparent_char(mut self, transparent_char: Option<char>) -> ImageConverter {
+        self.transparent_char = transparent_char;
+        return self;
+    }
+
+    /// Get char map size in cells for an image
+    pub fn get_size(&self, image: &Image) -> (usize, usize) {
+        let width = self.width.unwrap_or(image.get_width()).max(1);
+        let scale = image.get_width() as f32 / width as f32;
+        let height = (image.get_height() as f32 / (scale * self.cell_aspect)).round() as usize;
+        return (height.max(1), width);
+    }
+
+    /// Convert an image to a char map, colored if the image has colors and coloring is enabled
+    pub fn convert(&self, image: &Image) -> CharMapAsset {
+        let (height, width) = self.get_size(image);
+        let blank = self.transparent_char.unwrap_or(' ');
+        let mut chars = Array2::from_elem((height, width), blank);
+        let mut cells = Array2::from_elem((height, width), Cell::new(blank));
+
+        for y in 0..height {
+            for x in 0..width {
+                // Pixels covered by the cell, at least one
+                let left = x * image.get_width() / width;
+                let right = ((x + 1) * image.get_width() / width).max(left + 1);
+                let top = y * image.get_height() / height;
+                let bottom = ((y + 1) * image.get_height() / height).max(top + 1);
+
+                // Sums of huge cells do not fit 32 bits
+                let (mut r, mut g, mut b, mut a, mut count) = (0u64, 0u64, 0u64, 0u64, 0u64);
+                for pixel_y in top..bottom {
+                    for pixel_x in left..right {
+                        if let Some(pixel) = image.get_pixel(pixel_x, pixel_y) {
+                            r += pixel.r as u64;
+                            g += pixel.g as u64;
+                            b += pixel.b as u64;
+                            a += pixel.a as u64;
+                            count += 1;
+                        }
+                    }
+                }
+                if count == 0 || a / count < 128 {
+                    continue;
+                }
+                let color = Color32 {
+                    r: (r / count) as u32,
+                    g: (g / count) as u32,
+                    b: (b / count) as u32,
+                    a: 255,
+                };
+
+                let glyph = self.get_glyph(get_luminance(color));
+                chars[[y, x]] = glyph;
+                cells[[y, x]] = Cell::new(glyph).with_foreground(color);
+            }
+        }
+
+        let is_colored = self.is_colored && !image.is_grayscale();
+        return CharMapAsset::from_parts(
+            chars,
+            if is_colored { Some(cells) } else { None },
+            self.transparent_char,
+        );
+    }
+
+    /// Get the ramp character of a brightness between 0 and 1
+    fn get_glyph(&self, luminance: f32) -> char {
+        let luminance = if self.is_inverted {
+            1.0 - luminance
+        } else {
+            luminance
+        };
+        let last = self.ramp.len() - 1;
+        let index = (luminance * last as f32).round() as usize;
+        return self.ramp[index.min(last)];
+    }
+}
+
+/// Get perceived brightness of a color between 0 and 1
+fn get_luminance(color: Color32) -> f32 {
+    return (0.2126 * color.r as f32 + 0.7152 * color.g as f32 + 0.0722 * color.b as f32) / 255.0;
+}
@@ -0,0 +1,87 @@
+use super::{
+    char_map_loader::{CharMapAsset, CharMapLoader},
+    load_error::LoadError,
+};
+use crate::types::vector2int::Vector2Int;
+use std::collections::HashMap;
+
+/// A level: properties followed by a char map of tiles
+///
+/// Level files start with `key = value` lines, then a line holding the loader's frame
+/// delimiter (`---` by default) and the map, e.g. walls, floors and spawn points
+pub struct Level {
+    properties: HashMap<String, String>,
+    map: CharMapAsset,
+}
+
+impl Level {
+    /// Get a property value
+    pub fn get_property(&self, key: &str) -> Option<&str> {
+        return self.properties.get(key).map(|value| value.as_str());
+    }
+
+    /// Get every property
+    pub fn get_properties(&self) -> &HashMap<String, String> {
+        return &self.properties;
+    }
+
+    /// Get the tile map
+    pub fn get_map(&self) -> &CharMapAsset {
+        return &self.map;
+    }
+
+    /// Get positions of every tile holding a character, e.g. spawn points
+    pub fn find_tiles(&self, tile: char) -> Vec<Vector2Int> {
+        return self
+            .map
+            .get_chars()
+            .indexed_iter()
+            .filter(|(_, glyph)| **glyph == tile)
+            .map(|((y, x), _)| Vector2Int {
+                x: x as i32,
+                y: y as i32,
+            })
+            .collect();
+    }
+
+    /// Parse a level, source naming the text in errors
+    pub fn parse(text: &str, loader: &CharMapLoader, source: &str) -> Result<Level, LoadError> {
+        let mut properties = HashMap::new();
+        let mut lines = text.lines().enumerate();
+        let mut map_start = None;
+        for (index, line) in lines.by_ref() {
+            if line.starts_with(loader.get_frame_delimiter()) {
+                map_start = Some(index + 1);
+                break;
+            }
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once('=') {
+                Some((key, value)) => {
+                    properties.insert(key.trim().to_string(), value.trim().to_string());
+                }
+                None => {
+                    return Err(LoadError::InvalidFormat {
+                        source: source.to_string(),
+                        line: index + 1,
+                        reason: "expected `key = value` or the map delimiter".to_string(),
+                    })
+                }
+            }
+        }
+
+        let map_start = match map_start {
+            Some(map_start) => map_start,
+            None => {
+                return Err(LoadError::Empty {
+                    source: source.to_string(),
+                })
+            }
+        };
+        let map_text: Vec<&str> = lines.map(|(_, line)| line).collect();
+        let map = loader.parse_from_line(&map_text.join("\n"), map_start + 1, source)?;
+        return Ok(Level { properties, map });
+    }
+}
@@ -0,0 +1,121 @@
+use std::{error::Error, fmt, io};
+
+#[derive(Debug)]
+/// Why an asset could not be loaded, sources are file paths or names given to parsers
+pub enum LoadError {
+    /// The file could not be read
+    Io { source: String, error: io::Error },
+    /// There is nothing to load
+    Empty { source: String },
+    /// A line is not as wide as the first one
+    Ragged {
+        source: String,
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A color layer is not the same size as its char map, or sprite sheet frame
+    ColorLayerMismatch {
+        source: String,
+        frame: Option<String>,
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// A sprite sheet frame has no color layer frame of the same name, or the other way around
+    MissingFrame { source: String, name: String },
+    /// A color layer uses a key missing from the palette
+    UnknownColor {
+        source: String,
+        line: usize,
+        column: usize,
+        key: char,
+    },
+    /// An image is not given as many pixels as its size needs
+    PixelCount {
+        width: usize,
+        height: usize,
+        found: usize,
+    },
+    /// Two frames of a sprite sheet have the same name
+    DuplicateFrame { source: String, name: String },
+    /// A line is not in the expected format
+    InvalidFormat {
+        source: String,
+        line: usize,
+        reason: String,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io { source, error } => write!(formatter, "{}: {}", source, error),
+            LoadError::Empty { source } => write!(formatter, "{}: nothing to load", source),
+            LoadError::Ragged {
+                source,
+                line,
+                expected,
+                found,
+            } => write!(
+                formatter,
+                "{}:{}: line is {} characters wide, expected {}",
+                source, line, found, expected
+            ),
+            LoadError::ColorLayerMismatch {
+                source,
+                frame,
+                expected,
+                found,
+            } => {
+                write!(formatter, "{}: color layer ", source)?;
+                if let Some(frame) = frame {
+                    write!(formatter, "of frame '{}' ", frame)?;
+                }
+                write!(
+                    formatter,
+                    "is {}x{}, expected {}x{}",
+                    found.1, found.0, expected.1, expected.0
+                )
+            }
+            LoadError::UnknownColor {
+                source,
+                line,
+                column,
+                key,
+            } => write!(
+                formatter,
+                "{}:{}:{}: unknown color key '{}'",
+                source, line, column, key
+            ),
+            LoadError::PixelCount {
+                width,
+                height,
+                found,
+            } => write!(
+                formatter,
+                "{} pixels given for a {}x{} image",
+                found, width, height
+            ),
+            LoadError::DuplicateFrame { source, name } => {
+                write!(formatter, "{}: duplicate frame '{}'", source, name)
+            }
+            LoadError::MissingFrame { source, name } => {
+                write!(formatter, "{}: missing frame '{}'", source, name)
+            }
+            LoadError::InvalidFormat {
+                source,
+                line,
+                reason,
+            } => write!(formatter, "{}:{}: {}", source, line, reason),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            LoadError::Io { error, .. } => Some(error),
+            _ => None,
+        };
+    }
+}
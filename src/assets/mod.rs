@@ -0,0 +1,7 @@
+pub mod asset_manager;
+pub mod char_map_loader;
+pub mod image;
+pub mod image_converter;
+pub mod level;
+pub mod load_error;
+pub mod palette;
@@ -0,0 +1,66 @@
+use super::load_error::LoadError;
+use crate::types::color32::Color32;
+use std::collections::HashMap;
+
+/// Colors by key, as used by color layers
+///
+/// Palette files have one `key = rrggbb` line per color, empty lines and lines
+/// starting with `#` are ignored
+pub struct Palette {
+    colors: HashMap<char, Color32>,
+}
+
+impl Palette {
+    /// Default constructor, an empty palette
+    pub fn new() -> Self {
+        return Self {
+            colors: HashMap::new(),
+        };
+    }
+
+    /// Get color of a key
+    pub fn get_color(&self, key: char) -> Option<Color32> {
+        return self.colors.get(&key).copied();
+    }
+
+    /// Set color of a key
+    pub fn set_color(&mut self, key: char, color: Color32) {
+        self.colors.insert(key, color);
+    }
+
+    /// Get every key and its color
+    pub fn get_colors(&self) -> &HashMap<char, Color32> {
+        return &self.colors;
+    }
+
+    /// Parse a palette, source naming the text in errors
+    pub fn parse(text: &str, source: &str) -> Result<Palette, LoadError> {
+        let mut palette = Palette::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: &str| LoadError::InvalidFormat {
+                source: source.to_string(),
+                line: index + 1,
+                reason: reason.to_string(),
+            };
+
+            let (key, hex) = line
+                .split_once('=')
+                .ok_or_else(|| invalid("expected `key = rrggbb`"))?;
+            let mut keys = key.trim().chars();
+            let key = match (keys.next(), keys.next()) {
+                (Some(key), None) => key,
+                _ => return Err(invalid("key has to be a single character")),
+            };
+            let hex = hex.trim().trim_start_matches('#');
+            if hex.len() != 6 || !hex.chars().all(|digit| digit.is_ascii_hexdigit()) {
+                return Err(invalid("color has to be 6 hexadecimal digits"));
+            }
+            palette.set_color(key, Color32::from_hex(hex));
+        }
+        return Ok(palette);
+    }
+}
@@ -0,0 +1,202 @@
+#[cfg(unix)]
+use super::terminal_input::{TerminalInput, MOUSE_TRACKING_OFF, MOUSE_TRACKING_ON};
+use super::Backend;
+use crate::{
+    app::CursorMode,
+    input::InputEvent,
+    rendering::{cell::Cell, color_depth::ColorDepth, style::Style},
+    types::{color32::Color32, vector2int::Vector2Int},
+};
+use std::io::{self, Stdout, Write};
+
+/// Backend that writes ANSI escape codes to any writer
+///
+/// Useful for terminals curses does not support well, for streaming frames to a remote
+/// terminal, and for 24-bit color output. It only reads input when made with `terminal`
+pub struct AnsiBackend<W: Write> {
+    writer: W,
+    size: Vector2Int,
+    color_depth: ColorDepth,
+    output: Vec<u8>,
+    cursor: Option<Vector2Int>,
+    colors: Option<(Color32, Color32, Style)>,
+    #[cfg(unix)]
+    input: Option<TerminalInput>,
+}
+
+impl AnsiBackend<Stdout> {
+    /// Constructor writing to standard output with the detected terminal color depth
+    pub fn stdout(size: Vector2Int) -> Self {
+        return Self::new(io::stdout(), size, ColorDepth::detect());
+    }
+
+    /// Constructor for the current terminal, writing to standard output with the detected
+    /// color depth and reading keys, mouse and resizes from standard input
+    ///
+    /// Unlike curses, it draws 24-bit colors on terminals that support them
+    #[cfg(unix)]
+    pub fn terminal(size: Vector2Int) -> Self {
+        let mut backend = Self::stdout(size);
+        backend.input = Some(TerminalInput::new());
+        return backend;
+    }
+}
+
+impl<W: Write> AnsiBackend<W> {
+    /// Default constructor
+    pub fn new(writer: W, size: Vector2Int, color_depth: ColorDepth) -> Self {
+        return Self {
+            writer,
+            size,
+            color_depth,
+            output: Vec::new(),
+            cursor: None,
+            colors: None,
+            #[cfg(unix)]
+            input: None,
+        };
+    }
+
+    /// Get the underlying writer
+    pub fn get_writer(&self) -> &W {
+        return &self.writer;
+    }
+
+    /// Queue raw output, written on the next present
+    fn write(&mut self, text: &str) {
+        self.output.extend_from_slice(text.as_bytes());
+    }
+
+    /// Write buffered output to the writer
+    fn flush(&mut self) {
+        // Output is best effort, a closed writer simply stops showing frames
+        let _ = self.writer.write_all(&self.output);
+        let _ = self.writer.flush();
+        self.output.clear();
+    }
+
+    /// Escape code parameters selecting a color, for foreground (base 30) or background (base 40)
+    fn to_color_code(&self, color: Color32, base: u32) -> String {
+        if color.is_transparent() {
+            return format!("{}", base + 9);
+        }
+        return match self.color_depth {
+            ColorDepth::TrueColor => {
+                let color = { color }.normalized();
+                format!("{};2;{};{};{}", base + 8, color.r, color.g, color.b)
+            }
+            ColorDepth::Ansi256 => format!("{};5;{}", base + 8, color.to_ansi256()),
+            ColorDepth::Ansi8 => format!("{}", base + color.to_ansi8() as u32),
+        };
+    }
+}
+
+impl<W: Write> Backend for AnsiBackend<W> {
+    fn init(&mut self) {
+        // Switch to the alternate screen
+        self.write("\x1b[?1049h");
+        #[cfg(unix)]
+        if let Some(input) = &mut self.input {
+            input.enable();
+            self.write(MOUSE_TRACKING_ON);
+        }
+        self.clear();
+        self.flush();
+    }
+
+    fn shutdown(&mut self) {
+        #[cfg(unix)]
+        if let Some(input) = &mut self.input {
+            input.disable();
+            self.write(MOUSE_TRACKING_OFF);
+        }
+        // Reset colors, show cursor and leave the alternate screen
+        self.write("\x1b[0m\x1b[?25h\x1b[?1049l");
+        self.flush();
+    }
+
+    fn draw_cell(&mut self, position: Vector2Int, cell: Cell) {
+        if position.x < 0
+            || position.y < 0
+            || position.x >= self.size.x
+            || position.y >= self.size.y
+        {
+            return;
+        }
+
+        if self.cursor != Some(position) {
+            let code = format!("\x1b[{};{}H", position.y + 1, position.x + 1);
+            self.write(&code);
+        }
+        if self.colors != Some((cell.foreground, cell.background, cell.style)) {
+            // Reset attributes, then set the cell's attributes and colors
+            let mut code = String::from("\x1b[0");
+            for attribute in Style::ALL.iter() {
+                if cell.style.contains(*attribute) {
+                    if let Some(sgr_code) = attribute.to_sgr_code() {
+                        code += &format!(";{}", sgr_code);
+                    }
+                }
+            }
+            code += &format!(
+                ";{};{}m",
+                self.to_color_code(cell.foreground, 30),
+                self.to_color_code(cell.background, 40)
+            );
+            self.write(&code);
+            self.colors = Some((cell.foreground, cell.background, cell.style));
+        }
+
+        let mut glyph = [0; 4];
+        self.write(cell.glyph.encode_utf8(&mut glyph));
+        self.cursor = Some(Vector2Int {
+            x: position.x + 1,
+            y: position.y,
+        });
+    }
+
+    fn clear(&mut self) {
+        self.write("\x1b[0m\x1b[2J");
+        self.cursor = None;
+        self.colors = None;
+    }
+
+    fn present(&mut self) {
+        self.flush();
+    }
+
+    fn poll_input(&mut self) -> Option<InputEvent> {
+        #[cfg(unix)]
+        if let Some(input) = &mut self.input {
+            return input.poll();
+        }
+        return None;
+    }
+
+    fn get_size(&self) -> Vector2Int {
+        return self.size;
+    }
+
+    fn set_size(&mut self, size: Vector2Int) {
+        self.size = size;
+        let code = format!("\x1b[8;{};{}t", size.y, size.x);
+        self.write(&code);
+    }
+
+    fn set_title(&mut self, title: &str) {
+        let code = format!("\x1b]0;{}\x07", title);
+        self.write(&code);
+    }
+
+    fn get_color_depth(&self) -> ColorDepth {
+        return self.color_depth;
+    }
+
+    fn set_cursor_mode(&mut self, mode: CursorMode) {
+        match mode {
+            CursorMode::Hidden => self.write("\x1b[?25l"),
+            CursorMode::Normal => self.write("\x1b[?25h\x1b[0 q"),
+            CursorMode::Block => self.write("\x1b[?25h\x1b[2 q"),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+/// A foreground and background color number combination assigned to a pair number
+struct PairSlot {
+    pair: i16,
+    last_used: u64,
+}
+
+/// Allocates curses color pairs on demand for any foreground and background combination
+///
+/// Pairs are cached, and once the terminal's pair limit is reached the least recently
+/// used pair is recycled for the new combination
+pub struct ColorPairs {
+    capacity: i16,
+    slots: HashMap<(i16, i16), PairSlot>,
+    recycled: Vec<i16>,
+    tick: u64,
+}
+
+impl ColorPairs {
+    /// Default constructor, pair_count being the number of pairs the terminal supports
+    pub fn new(pair_count: i32) -> Self {
+        // Pair 0 is reserved for the terminal's default colors
+        let capacity = (pair_count - 1).clamp(0, i16::MAX as i32) as i16;
+        return Self {
+            capacity,
+            slots: HashMap::new(),
+            recycled: Vec::new(),
+            tick: 0,
+        };
+    }
+
+    /// Get number of pairs that can be allocated
+    pub fn get_capacity(&self) -> i16 {
+        return self.capacity;
+    }
+
+    /// Get number of pairs currently allocated
+    pub fn get_allocated(&self) -> usize {
+        return self.slots.len();
+    }
+
+    /// Get the pair number for a color combination
+    ///
+    /// The second value is true if the pair is newly assigned and has to be initialized
+    pub fn get_pair(&mut self, foreground: i16, background: i16) -> (i16, bool) {
+        self.tick += 1;
+        if self.capacity == 0 {
+            return (0, false);
+        }
+
+        // Reuse a cached pair
+        if let Some(slot) = self.slots.get_mut(&(foreground, background)) {
+            slot.last_used = self.tick;
+            return (slot.pair, false);
+        }
+
+        // Allocate a new pair, or recycle the least recently used one
+        let pair = if (self.slots.len() as i16) < self.capacity {
+            self.slots.len() as i16 + 1
+        } else {
+            let oldest = *self
+                .slots
+                .iter()
+                .min_by_key(|(_, slot)| slot.last_used)
+                .map(|(colors, _)| colors)
+                .unwrap();
+            let slot = self.slots.remove(&oldest).unwrap();
+            self.recycled.push(slot.pair);
+            slot.pair
+        };
+        self.slots.insert(
+            (foreground, background),
+            PairSlot {
+                pair,
+                last_used: self.tick,
+            },
+        );
+        return (pair, true);
+    }
+
+    /// Take pair numbers that were recycled since the last call
+    ///
+    /// Anything already on screen using these pairs now shows the wrong colors
+    pub fn take_recycled(&mut self) -> Vec<i16> {
+        return std::mem::take(&mut self.recycled);
+    }
+
+    /// Forget all allocated pairs
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.recycled.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caches_pairs_by_colors() {
+        let mut pairs = ColorPairs::new(8);
+        assert_eq!(pairs.get_capacity(), 7);
+        assert_eq!(pairs.get_pair(1, 0), (1, true));
+        assert_eq!(pairs.get_pair(2, 0), (2, true));
+        assert_eq!(pairs.get_pair(1, 0), (1, false));
+        assert_eq!(pairs.get_pair(0, 1), (3, true));
+        assert_eq!(pairs.get_allocated(), 3);
+        assert!(pairs.take_recycled().is_empty());
+    }
+
+    #[test]
+    fn recycles_least_recently_used_pair() {
+        let mut pairs = ColorPairs::new(4);
+        pairs.get_pair(1, 0);
+        pairs.get_pair(2, 0);
+        pairs.get_pair(3, 0);
+        // Using the first pair again leaves the second as the oldest
+        pairs.get_pair(1, 0);
+
+        assert_eq!(pairs.get_pair(4, 0), (2, true));
+        assert_eq!(pairs.get_allocated(), 3);
+        assert_eq!(pairs.take_recycled(), vec![2]);
+        assert!(pairs.take_recycled().is_empty());
+
+        // The recycled combination gets a pair again when used
+        assert_eq!(pairs.get_pair(2, 0), (3, true));
+        assert_eq!(pairs.get_pair(1, 0), (1, false));
+        assert_eq!(pairs.take_recycled(), vec![3]);
+    }
+
+    #[test]
+    fn gives_default_pair_without_capacity() {
+        for pair_count in [0, 1, -5].iter() {
+            let mut pairs = ColorPairs::new(*pair_count);
+            assert_eq!(pairs.get_capacity(), 0);
+            assert_eq!(pairs.get_pair(1, 2), (0, false));
+            assert_eq!(pairs.get_allocated(), 0);
+        }
+        assert_eq!(ColorPairs::new(i32::MAX).get_capacity(), i16::MAX);
+    }
+
+    #[test]
+    fn clear_forgets_pairs() {
+        let mut pairs = ColorPairs::new(2);
+        pairs.get_pair(1, 0);
+        pairs.get_pair(2, 0);
+        pairs.clear();
+        assert_eq!(pairs.get_allocated(), 0);
+        assert!(pairs.take_recycled().is_empty());
+        assert_eq!(pairs.get_pair(2, 0), (1, true));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::input::{Modifiers, SpecialKey};
+
+/// Decode a key and its modifiers from an escape sequence sent by a terminal,
+/// given without its leading escape character, e.g. `[A` or `[1;5A` for Ctrl+Up
+///
+/// Understands the CSI (`[`) and SS3 (`O`) sequences of xterm-like terminals and
+/// the Linux console
+pub(crate) fn decode(sequence: &str) -> Option<(SpecialKey, Modifiers)> {
+    let mut chars = sequence.chars();
+    let introducer = chars.next()?;
+    let body = chars.as_str();
+    let final_char = body.chars().last()?;
+    let parameters = &body[..body.len() - final_char.len_utf8()];
+
+    match introducer {
+        // SS3, a letter which some terminals precede with a modifier parameter
+        'O' => {
+            let modifiers = parse_modifiers(parameters)?;
+            return decode_letter(final_char).map(|key| (key, modifiers));
+        }
+        // CSI, numeric parameters then a final character
+        '[' => {}
+        _ => return None,
+    }
+
+    // Linux console function keys
+    if parameters == "[" {
+        let key = match final_char {
+            'A' => SpecialKey::F1,
+            'B' => SpecialKey::F2,
+            'C' => SpecialKey::F3,
+            'D' => SpecialKey::F4,
+            'E' => SpecialKey::F5,
+            _ => return None,
+        };
+        return Some((key, Modifiers::NONE));
+    }
+
+    // The key is given by the first parameter or the final letter, modifiers by the second parameter
+    let mut parameters = parameters.split(';');
+    let number = parameters.next().unwrap_or("");
+    let modifiers = parse_modifiers(parameters.next().unwrap_or(""))?;
+    let key = if final_char == '~' {
+        decode_number(number.parse().ok()?)?
+    } else {
+        decode_letter(final_char)?
+    };
+    return Some((key, modifiers));
+}
+
+/// Decode a key and its modifiers from the name curses gives an extended key,
+/// e.g. `kUP5` for Ctrl+Up or `kDC2` for Shift+Delete
+pub(crate) fn decode_key_name(name: &str) -> Option<(SpecialKey, Modifiers)> {
+    let name = name.strip_prefix('k')?;
+    let split = name.find(|char: char| char.is_ascii_digit())?;
+    let (base, parameter) = name.split_at(split);
+    let key = match base {
+        "UP" => SpecialKey::Up,
+        "DN" => SpecialKey::Down,
+        "LFT" => SpecialKey::Left,
+        "RIT" => SpecialKey::Right,
+        "HOM" => SpecialKey::Home,
+        "END" => SpecialKey::End,
+        "IC" => SpecialKey::Insert,
+        "DC" => SpecialKey::Delete,
+        "PRV" => SpecialKey::PageUp,
+        "NXT" => SpecialKey::PageDown,
+        _ => return None,
+    };
+    return Some((key, parse_modifiers(parameter)?));
+}
+
+/// Parse an xterm modifier parameter, no parameter means no modifiers
+fn parse_modifiers(parameter: &str) -> Option<Modifiers> {
+    if parameter.is_empty() {
+        return Some(Modifiers::NONE);
+    }
+    return Some(Modifiers::from_xterm_parameter(parameter.parse().ok()?));
+}
+
+/// Decode a key from the final letter of a sequence
+fn decode_letter(letter: char) -> Option<SpecialKey> {
+    return match letter {
+        'A' => Some(SpecialKey::Up),
+        'B' => Some(SpecialKey::Down),
+        'C' => Some(SpecialKey::Right),
+        'D' => Some(SpecialKey::Left),
+        'E' => Some(SpecialKey::KeypadCenter),
+        'H' => Some(SpecialKey::Home),
+        'F' => Some(SpecialKey::End),
+        'M' => Some(SpecialKey::KeypadEnter),
+        'P' => Some(SpecialKey::F1),
+        'Q' => Some(SpecialKey::F2),
+        'R' => Some(SpecialKey::F3),
+        'S' => Some(SpecialKey::F4),
+        'Z' => Some(SpecialKey::BackTab),
+        _ => None,
+    };
+}
+
+/// Decode a key from the number of a sequence ending with `~`
+fn decode_number(number: u32) -> Option<SpecialKey> {
+    return match number {
+        1 | 7 => Some(SpecialKey::Home),
+        2 => Some(SpecialKey::Insert),
+        3 => Some(SpecialKey::Delete),
+        4 | 8 => Some(SpecialKey::End),
+        5 => Some(SpecialKey::PageUp),
+        6 => Some(SpecialKey::PageDown),
+        11..=15 => SpecialKey::function((number - 10) as u8),
+        17..=21 => SpecialKey::function((number - 11) as u8),
+        23 | 24 => SpecialKey::function((number - 12) as u8),
+        _ => None,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_arrow_and_navigation_keys() {
+        assert_eq!(decode("[A"), Some((SpecialKey::Up, Modifiers::NONE)));
+        assert_eq!(decode("OD"), Some((SpecialKey::Left, Modifiers::NONE)));
+        assert_eq!(decode("[H"), Some((SpecialKey::Home, Modifiers::NONE)));
+        assert_eq!(decode("[4~"), Some((SpecialKey::End, Modifiers::NONE)));
+        assert_eq!(decode("[3~"), Some((SpecialKey::Delete, Modifiers::NONE)));
+        assert_eq!(decode("[6~"), Some((SpecialKey::PageDown, Modifiers::NONE)));
+        assert_eq!(decode("[Z"), Some((SpecialKey::BackTab, Modifiers::NONE)));
+    }
+
+    #[test]
+    fn decodes_function_keys() {
+        assert_eq!(decode("OP"), Some((SpecialKey::F1, Modifiers::NONE)));
+        assert_eq!(decode("OS"), Some((SpecialKey::F4, Modifiers::NONE)));
+        assert_eq!(decode("[15~"), Some((SpecialKey::F5, Modifiers::NONE)));
+        assert_eq!(decode("[17~"), Some((SpecialKey::F6, Modifiers::NONE)));
+        assert_eq!(decode("[24~"), Some((SpecialKey::F12, Modifiers::NONE)));
+        // Linux console
+        assert_eq!(decode("[[A"), Some((SpecialKey::F1, Modifiers::NONE)));
+        assert_eq!(decode("[[E"), Some((SpecialKey::F5, Modifiers::NONE)));
+    }
+
+    #[test]
+    fn rejects_unknown_sequences() {
+        assert_eq!(decode(""), None);
+        assert_eq!(decode("["), None);
+        assert_eq!(decode("[16~"), None);
+        assert_eq!(decode("[99~"), None);
+        assert_eq!(decode("[X"), None);
+        assert_eq!(decode("Xa"), None);
+    }
+
+    #[test]
+    fn decodes_modifier_parameters() {
+        assert_eq!(decode("[1;5A"), Some((SpecialKey::Up, Modifiers::CTRL)));
+        assert_eq!(decode("[1;2D"), Some((SpecialKey::Left, Modifiers::SHIFT)));
+        assert_eq!(decode("[3;3~"), Some((SpecialKey::Delete, Modifiers::ALT)));
+        assert_eq!(
+            decode("[15;6~"),
+            Some((SpecialKey::F5, Modifiers::CTRL | Modifiers::SHIFT))
+        );
+        assert_eq!(decode("O5P"), Some((SpecialKey::F1, Modifiers::CTRL)));
+        assert_eq!(decode("[1;x5A"), None);
+    }
+
+    #[test]
+    fn decodes_curses_key_names() {
+        assert_eq!(
+            decode_key_name("kUP5"),
+            Some((SpecialKey::Up, Modifiers::CTRL))
+        );
+        assert_eq!(
+            decode_key_name("kDC2"),
+            Some((SpecialKey::Delete, Modifiers::SHIFT))
+        );
+        assert_eq!(
+            decode_key_name("kNXT7"),
+            Some((SpecialKey::PageDown, Modifiers::CTRL | Modifiers::ALT))
+        );
+        assert_eq!(decode_key_name("kUP"), None);
+        assert_eq!(decode_key_name("kFOO3"), None);
+        assert_eq!(decode_key_name("UP5"), None);
+    }
+}
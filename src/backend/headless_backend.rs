@@ -0,0 +1,157 @@
+use super::Backend;
+use crate::{
+    app::CursorMode,
+    input::InputEvent,
+    rendering::{cell::Cell, color_depth::ColorDepth, frame_buffer::FrameBuffer},
+    types::vector2int::Vector2Int,
+};
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+/// Screen and input state shared between clones of a HeadlessBackend
+struct HeadlessState {
+    title: String,
+    cursor_mode: CursorMode,
+    screen: FrameBuffer,
+    presented: FrameBuffer,
+    frame_count: u64,
+    pending_writes: usize,
+    cells_written: usize,
+    input_queue: VecDeque<InputEvent>,
+}
+
+#[derive(Clone)]
+/// Backend that renders into an in-memory cell grid instead of a terminal
+///
+/// Clones share the same screen and input queue, so a clone kept outside of the App
+/// can feed input and inspect frames while the App is running
+pub struct HeadlessBackend {
+    state: Rc<RefCell<HeadlessState>>,
+}
+
+impl HeadlessBackend {
+    /// Default constructor
+    pub fn new(size: Vector2Int) -> Self {
+        let state = HeadlessState {
+            title: String::new(),
+            cursor_mode: CursorMode::Normal,
+            screen: FrameBuffer::new(size, Cell::blank()),
+            presented: FrameBuffer::new(size, Cell::blank()),
+            frame_count: 0,
+            pending_writes: 0,
+            cells_written: 0,
+            input_queue: VecDeque::new(),
+        };
+        return Self {
+            state: Rc::new(RefCell::new(state)),
+        };
+    }
+
+    /// Queue an input event to be returned by a following poll
+    pub fn push_input(&self, event: InputEvent) {
+        self.state.borrow_mut().input_queue.push_back(event);
+    }
+
+    /// Queue a key press for every character in a string, "\n" presses Enter
+    pub fn push_str(&self, text: &str) {
+        for char in text.chars() {
+            self.push_input(InputEvent::from_char(char));
+        }
+    }
+
+    /// Get number of frames presented so far
+    pub fn get_frame_count(&self) -> u64 {
+        return self.state.borrow().frame_count;
+    }
+
+    /// Get number of cells drawn for the last presented frame
+    pub fn get_cells_written(&self) -> usize {
+        return self.state.borrow().cells_written;
+    }
+
+    /// Get a cell of the last presented frame
+    pub fn get_cell(&self, position: Vector2Int) -> Option<Cell> {
+        return self.state.borrow().presented.get(position);
+    }
+
+    /// Get a character of the last presented frame
+    pub fn get_char(&self, position: Vector2Int) -> Option<char> {
+        return self.get_cell(position).map(|cell| cell.glyph);
+    }
+
+    /// Get a row of the last presented frame as a string
+    pub fn get_row(&self, y: i32) -> String {
+        let width = self.state.borrow().presented.get_size().x;
+        return (0..width)
+            .filter_map(|x| self.get_char(Vector2Int { x, y }))
+            .collect();
+    }
+
+    /// Get the whole last presented frame as lines of text
+    pub fn get_text(&self) -> String {
+        let height = self.state.borrow().presented.get_size().y;
+        return (0..height)
+            .map(|y| self.get_row(y))
+            .collect::<Vec<String>>()
+            .join("\n");
+    }
+
+    /// Get current screen title
+    pub fn get_title(&self) -> String {
+        return self.state.borrow().title.clone();
+    }
+
+    /// Get current cursor display mode
+    pub fn get_cursor_mode(&self) -> CursorMode {
+        return self.state.borrow().cursor_mode;
+    }
+}
+
+impl Backend for HeadlessBackend {
+    fn init(&mut self) {}
+
+    fn shutdown(&mut self) {}
+
+    fn draw_cell(&mut self, position: Vector2Int, cell: Cell) {
+        let mut state = self.state.borrow_mut();
+        state.screen.set(position, cell);
+        state.pending_writes += 1;
+    }
+
+    fn clear(&mut self) {
+        self.state.borrow_mut().screen.fill(Cell::blank());
+    }
+
+    fn present(&mut self) {
+        let mut state = self.state.borrow_mut();
+        state.presented = state.screen.clone();
+        state.frame_count += 1;
+        state.cells_written = state.pending_writes;
+        state.pending_writes = 0;
+    }
+
+    fn poll_input(&mut self) -> Option<InputEvent> {
+        return self.state.borrow_mut().input_queue.pop_front();
+    }
+
+    fn get_size(&self) -> Vector2Int {
+        return self.state.borrow().screen.get_size();
+    }
+
+    fn set_size(&mut self, size: Vector2Int) {
+        let mut state = self.state.borrow_mut();
+        state.screen.resize(size, Cell::blank());
+        state.presented.resize(size, Cell::blank());
+    }
+
+    fn set_title(&mut self, title: &str) {
+        self.state.borrow_mut().title = title.to_string();
+    }
+
+    fn get_color_depth(&self) -> ColorDepth {
+        return ColorDepth::TrueColor;
+    }
+
+    fn set_cursor_mode(&mut self, mode: CursorMode) {
+        self.state.borrow_mut().cursor_mode = mode;
+    }
+}
@@ -0,0 +1,58 @@
+pub mod ansi_backend;
+pub mod color_pairs;
+mod escape_sequence;
+pub mod headless_backend;
+pub mod pancurses_backend;
+#[cfg(unix)]
+mod terminal_input;
+
+use crate::{
+    app::CursorMode,
+    input::InputEvent,
+    rendering::{cell::Cell, color_depth::ColorDepth},
+    types::vector2int::Vector2Int,
+};
+
+/// A terminal (or terminal-like) target that an App renders to and reads input from
+pub trait Backend {
+    /// Prepare the backend before the App starts running
+    fn init(&mut self);
+
+    /// Restore the backend after the App stops running
+    fn shutdown(&mut self);
+
+    /// Draw a single cell at a position
+    fn draw_cell(&mut self, position: Vector2Int, cell: Cell);
+
+    /// Clear everything that has been drawn
+    fn clear(&mut self);
+
+    /// Show every cell drawn since the last present
+    fn present(&mut self);
+
+    /// Return the next pending input event, if any
+    fn poll_input(&mut self) -> Option<InputEvent>;
+
+    /// Get screen size in cells
+    fn get_size(&self) -> Vector2Int;
+
+    /// Set screen size in cells
+    fn set_size(&mut self, size: Vector2Int);
+
+    /// Set screen title
+    fn set_title(&mut self, title: &str);
+
+    /// Get how many colors the backend is able to display
+    ///
+    /// Cell colors are reduced to this depth when drawn
+    fn get_color_depth(&self) -> ColorDepth;
+
+    /// Set how the cursor is displayed
+    fn set_cursor_mode(&mut self, mode: CursorMode);
+
+    /// Invert the screen colors for a split second
+    fn flash(&self) {}
+
+    /// Play a beep sound
+    fn beep(&self) {}
+}
@@ -0,0 +1,491 @@
+use std::{
+    collections::VecDeque,
+    env,
+    io::{self, Write},
+};
+
+use super::{color_pairs::ColorPairs, escape_sequence, Backend};
+use crate::{
+    app::CursorMode,
+    input::{InputEvent, Key, Modifiers, MouseEvent, SpecialKey},
+    rendering::{cell::Cell, color_depth::ColorDepth, frame_buffer::FrameBuffer, style::Style},
+    types::{color32::Color32, vector2int::Vector2Int},
+};
+use pancurses::{
+    beep, chtype, curs_set, endwin, flash, getmouse, has_colors, init_pair, initscr, mousemask,
+    noecho, resize_term, set_title, start_color, use_default_colors, Window, ACS_HLINE,
+    ACS_LLCORNER, ACS_LRCORNER, ACS_ULCORNER, ACS_URCORNER, ACS_VLINE, ALL_MOUSE_EVENTS, A_BLINK,
+    A_BOLD, A_DIM, A_ITALIC, A_NORMAL, A_REVERSE, A_UNDERLINE, BUTTON1_CLICKED,
+    BUTTON1_DOUBLE_CLICKED, BUTTON1_PRESSED, BUTTON1_RELEASED, BUTTON2_CLICKED,
+    BUTTON2_DOUBLE_CLICKED, BUTTON2_PRESSED, BUTTON2_RELEASED, BUTTON3_CLICKED,
+    BUTTON3_DOUBLE_CLICKED, BUTTON3_PRESSED, BUTTON3_RELEASED, BUTTON4_PRESSED, BUTTON5_PRESSED,
+    COLORS, COLOR_PAIR, COLOR_PAIRS, REPORT_MOUSE_POSITION,
+};
+
+// Milliseconds curses waits for the rest of an escape sequence, short enough for
+// the Escape key to feel instant
+const ESCAPE_DELAY: u32 = 25;
+
+/// Backend that renders to the current terminal through curses, in up to 256 colors
+pub struct PancursesBackend {
+    window: Window,
+    color_depth: ColorDepth,
+    color_pairs: ColorPairs,
+    attributes: Option<chtype>,
+
+    // Cells on screen and the color pair each was drawn with,
+    // used to redraw cells whose color pair got recycled
+    screen: FrameBuffer,
+    screen_pairs: Vec<i16>,
+
+    // Events decoded along with another one, e.g. from a single mouse report
+    pending_events: VecDeque<InputEvent>,
+}
+
+impl PancursesBackend {
+    /// Default constructor, initializes the curses screen
+    pub fn new() -> Self {
+        // ncurses reads the escape delay when initialized, unless the user set one
+        if env::var_os("ESCDELAY").is_none() {
+            env::set_var("ESCDELAY", ESCAPE_DELAY.to_string());
+        }
+        let window = initscr();
+        let size = Vector2Int {
+            x: window.get_max_x(),
+            y: window.get_max_y(),
+        };
+        return Self {
+            window,
+            color_depth: ColorDepth::Ansi8,
+            color_pairs: ColorPairs::new(0),
+            attributes: None,
+            screen: FrameBuffer::new(size, Cell::blank()),
+            screen_pairs: vec![0; (size.x * size.y).max(0) as usize],
+            pending_events: VecDeque::new(),
+        };
+    }
+
+    /// Read what follows an escape character: the rest of a sequence sent by the
+    /// terminal for a key, a key pressed with Alt, or nothing when the Escape key
+    /// was pressed
+    fn read_escape(&mut self) -> InputEvent {
+        let introducer = match self.poll_input() {
+            None => return InputEvent::Special(SpecialKey::Escape),
+            Some(InputEvent::Character(char)) if char == '[' || char == 'O' => char,
+            // Terminals send Alt combinations as the key preceded by an escape
+            Some(event) => return event.with_modifiers(Modifiers::ALT),
+        };
+
+        // CSI sequences end with a character between '@' and '~', besides the Linux
+        // console's "[[", SS3 sequences with the first character which is not a digit
+        let mut sequence = introducer.to_string();
+        loop {
+            let char = match self.window.getch() {
+                Some(pancurses::Input::Character(char)) => char,
+                Some(input) => {
+                    self.window.ungetch(&input);
+                    break;
+                }
+                None => break,
+            };
+            sequence.push(char);
+            let is_final = if introducer == 'O' {
+                !char.is_ascii_digit()
+            } else {
+                ('@'..='~').contains(&char) && sequence != "[["
+            };
+            if is_final {
+                break;
+            }
+        }
+
+        // Nothing after the introducer, Alt+[ or Alt+O was pressed
+        if sequence.len() == 1 {
+            return InputEvent::Modified(Key::Char(introducer), Modifiers::ALT);
+        }
+        return match escape_sequence::decode(&sequence) {
+            Some((special_key, modifiers)) => Self::to_key_event(special_key, modifiers),
+            None => InputEvent::Other,
+        };
+    }
+
+    /// Read a key which pancurses would mistake for a character: function keys
+    /// past F12 and extended keys such as "kUP5" (Ctrl+Up), named after their
+    /// modifiers. Any other input is put back for pancurses to read
+    #[cfg(unix)]
+    fn read_extended_key(&mut self) -> Option<InputEvent> {
+        let code = ncurses::getch();
+        if code == ncurses::ERR {
+            return None;
+        }
+
+        // Terminals report F1 to F12 with Shift as F13 to F24, Ctrl as F25 to F36 and so on
+        if (ncurses::KEY_F0 + 13..=ncurses::KEY_F0 + 63).contains(&code) {
+            let number = code - (ncurses::KEY_F0 + 13);
+            let key = SpecialKey::function((number % 12 + 1) as u8).unwrap();
+            let modifiers = match number / 12 {
+                0 => Modifiers::SHIFT,
+                1 => Modifiers::CTRL,
+                2 => Modifiers::CTRL | Modifiers::SHIFT,
+                3 => Modifiers::ALT,
+                _ => Modifiers::ALT | Modifiers::SHIFT,
+            };
+            return Some(Self::to_key_event(key, modifiers));
+        }
+        if code > ncurses::KEY_MAX {
+            let name = pancurses::keyname(code).unwrap_or_default();
+            return Some(match escape_sequence::decode_key_name(&name) {
+                Some((special_key, modifiers)) => Self::to_key_event(special_key, modifiers),
+                None => InputEvent::Other,
+            });
+        }
+
+        ncurses::ungetch(code);
+        return None;
+    }
+
+    /// Convert a curses key to a special key and its modifiers, None for keys lunar
+    /// does not know
+    fn to_key(input: pancurses::Input) -> Option<(SpecialKey, Modifiers)> {
+        let key = match input {
+            pancurses::Input::KeyUp => Some(SpecialKey::Up),
+            pancurses::Input::KeyDown => Some(SpecialKey::Down),
+            pancurses::Input::KeyLeft => Some(SpecialKey::Left),
+            pancurses::Input::KeyRight => Some(SpecialKey::Right),
+            pancurses::Input::KeyEnter => Some(SpecialKey::KeypadEnter),
+            pancurses::Input::KeyBackspace => Some(SpecialKey::Backspace),
+            pancurses::Input::KeyBTab => Some(SpecialKey::BackTab),
+            pancurses::Input::KeyIC => Some(SpecialKey::Insert),
+            pancurses::Input::KeyDC => Some(SpecialKey::Delete),
+            pancurses::Input::KeyHome => Some(SpecialKey::Home),
+            pancurses::Input::KeyEnd => Some(SpecialKey::End),
+            pancurses::Input::KeyPPage => Some(SpecialKey::PageUp),
+            pancurses::Input::KeyNPage => Some(SpecialKey::PageDown),
+            pancurses::Input::KeyF1 => Some(SpecialKey::F1),
+            pancurses::Input::KeyF2 => Some(SpecialKey::F2),
+            pancurses::Input::KeyF3 => Some(SpecialKey::F3),
+            pancurses::Input::KeyF4 => Some(SpecialKey::F4),
+            pancurses::Input::KeyF5 => Some(SpecialKey::F5),
+            pancurses::Input::KeyF6 => Some(SpecialKey::F6),
+            pancurses::Input::KeyF7 => Some(SpecialKey::F7),
+            pancurses::Input::KeyF8 => Some(SpecialKey::F8),
+            pancurses::Input::KeyF9 => Some(SpecialKey::F9),
+            pancurses::Input::KeyF10 => Some(SpecialKey::F10),
+            pancurses::Input::KeyF11 => Some(SpecialKey::F11),
+            pancurses::Input::KeyF12 => Some(SpecialKey::F12),
+            pancurses::Input::KeyA1 => Some(SpecialKey::KeypadUpLeft),
+            pancurses::Input::KeyA3 => Some(SpecialKey::KeypadUpRight),
+            pancurses::Input::KeyB2 => Some(SpecialKey::KeypadCenter),
+            pancurses::Input::KeyC1 => Some(SpecialKey::KeypadDownLeft),
+            pancurses::Input::KeyC3 => Some(SpecialKey::KeypadDownRight),
+            _ => None,
+        };
+        if let Some(key) = key {
+            return Some((key, Modifiers::NONE));
+        }
+
+        // Shifted keys curses knows about
+        let key = match input {
+            pancurses::Input::KeySR => SpecialKey::Up,
+            pancurses::Input::KeySF => SpecialKey::Down,
+            pancurses::Input::KeySLeft => SpecialKey::Left,
+            pancurses::Input::KeySRight => SpecialKey::Right,
+            pancurses::Input::KeySHome => SpecialKey::Home,
+            pancurses::Input::KeySEnd => SpecialKey::End,
+            pancurses::Input::KeySIC => SpecialKey::Insert,
+            pancurses::Input::KeySDC => SpecialKey::Delete,
+            pancurses::Input::KeySPrevious => SpecialKey::PageUp,
+            pancurses::Input::KeySNext => SpecialKey::PageDown,
+            _ => return None,
+        };
+        return Some((key, Modifiers::SHIFT));
+    }
+
+    /// Event of a special key, with modifiers if there are any
+    fn to_key_event(special_key: SpecialKey, modifiers: Modifiers) -> InputEvent {
+        if modifiers.is_empty() {
+            return InputEvent::Special(special_key);
+        }
+        return InputEvent::Modified(Key::Special(special_key), modifiers);
+    }
+
+    /// Turn off the terminal's XON/XOFF flow control so Ctrl+S and Ctrl+Q reach the App,
+    /// curses restores the terminal settings on shutdown
+    #[cfg(unix)]
+    fn disable_flow_control() {
+        unsafe {
+            let mut termios: libc::termios = std::mem::zeroed();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut termios) == 0 {
+                termios.c_iflag &= !libc::IXON;
+                libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &termios);
+            }
+        }
+    }
+
+    /// Get the color pair allocator
+    pub fn get_color_pairs(&self) -> &ColorPairs {
+        return &self.color_pairs;
+    }
+
+    /// Draw a cell and remember which color pair it used
+    fn put_cell(&mut self, position: Vector2Int, cell: Cell) {
+        let foreground = self.to_color_number(cell.foreground);
+        let background = self.to_color_number(cell.background);
+        let (pair, is_new) = self.color_pairs.get_pair(foreground, background);
+        if is_new {
+            init_pair(pair, foreground, background);
+        }
+
+        let attributes = COLOR_PAIR(pair as chtype) | Self::to_attributes(cell.style);
+        if self.attributes != Some(attributes) {
+            self.attributes = Some(attributes);
+            self.window.attrset(attributes);
+        }
+
+        if let Some(line) = Self::to_line_char(cell.glyph) {
+            self.window.mvaddch(position.y, position.x, line);
+        } else if cell.glyph.is_ascii() {
+            self.window.mvaddch(position.y, position.x, cell.glyph);
+        } else {
+            self.window
+                .mvaddstr(position.y, position.x, cell.glyph.to_string());
+        }
+
+        if self.screen.contains(position) {
+            self.screen.set(position, cell);
+            let index = (position.y * self.screen.get_size().x + position.x) as usize;
+            self.screen_pairs[index] = pair;
+        }
+    }
+
+    /// Redraw cells on screen whose color pair was recycled for other colors
+    fn redraw_recycled(&mut self) {
+        // Redrawing may recycle more pairs, give up after a few passes
+        for _ in 0..4 {
+            let recycled = self.color_pairs.take_recycled();
+            if recycled.is_empty() {
+                return;
+            }
+            let width = self.screen.get_size().x;
+            for index in 0..self.screen_pairs.len() {
+                if recycled.contains(&self.screen_pairs[index]) {
+                    let position = Vector2Int {
+                        x: index as i32 % width,
+                        y: index as i32 / width,
+                    };
+                    let cell = self.screen.get(position).unwrap();
+                    self.put_cell(position, cell);
+                }
+            }
+        }
+    }
+
+    /// Reset remembered screen cells to the current window size
+    fn reset_screen(&mut self) {
+        let size = self.get_size();
+        self.screen.resize(size, Cell::blank());
+        self.screen_pairs = vec![0; (size.x * size.y).max(0) as usize];
+    }
+
+    /// Get the underlying curses window
+    pub fn get_window(&self) -> &Window {
+        return &self.window;
+    }
+
+    /// Convert a color to the closest curses color number, -1 being the default color
+    fn to_color_number(&self, color: Color32) -> i16 {
+        if color.is_transparent() {
+            return -1;
+        }
+        return match self.color_depth {
+            ColorDepth::Ansi8 => color.to_ansi8() as i16,
+            _ => color.to_ansi256() as i16,
+        };
+    }
+
+    /// Convert text attributes to curses attributes
+    fn to_attributes(style: Style) -> chtype {
+        let mut attributes = A_NORMAL;
+        for (attribute, curses_attribute) in Style::ALL
+            .iter()
+            .zip([A_BOLD, A_DIM, A_ITALIC, A_UNDERLINE, A_BLINK, A_REVERSE].iter())
+        {
+            if style.contains(*attribute) {
+                attributes |= *curses_attribute;
+            }
+        }
+        return attributes;
+    }
+
+    /// Convert a box drawing glyph to the matching curses line character
+    fn to_line_char(glyph: char) -> Option<chtype> {
+        return match glyph {
+            '│' => Some(ACS_VLINE()),
+            '─' => Some(ACS_HLINE()),
+            '┌' => Some(ACS_ULCORNER()),
+            '┐' => Some(ACS_URCORNER()),
+            '└' => Some(ACS_LLCORNER()),
+            '┘' => Some(ACS_LRCORNER()),
+            _ => None,
+        };
+    }
+
+    /// Convert a curses mouse button state to mouse events, a single state can hold
+    /// several button changes. Motion is only reported when nothing else happened
+    fn to_mouse_events(bstate: pancurses::mmask_t) -> Vec<MouseEvent> {
+        let flags = [
+            (BUTTON1_PRESSED, MouseEvent::LeftMouseDown),
+            (BUTTON1_RELEASED, MouseEvent::LeftMouseUp),
+            (BUTTON1_CLICKED, MouseEvent::LeftMouseClick),
+            (BUTTON1_DOUBLE_CLICKED, MouseEvent::LeftMouseDoubleClick),
+            (BUTTON2_PRESSED, MouseEvent::MiddleMouseDown),
+            (BUTTON2_RELEASED, MouseEvent::MiddleMouseUp),
+            (BUTTON2_CLICKED, MouseEvent::MiddleMouseClick),
+            (BUTTON2_DOUBLE_CLICKED, MouseEvent::MiddleMouseDoubleClick),
+            (BUTTON3_PRESSED, MouseEvent::RightMouseDown),
+            (BUTTON3_RELEASED, MouseEvent::RightMouseUp),
+            (BUTTON3_CLICKED, MouseEvent::RightMouseClick),
+            (BUTTON3_DOUBLE_CLICKED, MouseEvent::RightMouseDoubleClick),
+            (BUTTON4_PRESSED, MouseEvent::WheelUp),
+            (BUTTON5_PRESSED, MouseEvent::WheelDown),
+        ];
+        let mut events: Vec<MouseEvent> = flags
+            .iter()
+            .filter(|(flag, _)| bstate & flag != 0)
+            .map(|(_, event)| *event)
+            .collect();
+        if events.is_empty() && bstate & REPORT_MOUSE_POSITION != 0 {
+            events.push(MouseEvent::Moved);
+        }
+        return events;
+    }
+
+    /// Enable or disable the terminal reporting every mouse motion, which curses
+    /// leaves to the terminal's description and most do not ask for
+    #[cfg(unix)]
+    fn set_motion_tracking(enabled: bool) {
+        let code = if enabled {
+            "\x1b[?1003h"
+        } else {
+            "\x1b[?1003l"
+        };
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(code.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+impl Backend for PancursesBackend {
+    fn init(&mut self) {
+        self.window.keypad(true);
+        self.window.nodelay(true);
+
+        // Listen to all mouse events, motion included
+        mousemask(ALL_MOUSE_EVENTS | REPORT_MOUSE_POSITION, None);
+        #[cfg(unix)]
+        Self::set_motion_tracking(true);
+        noecho();
+        if has_colors() {
+            start_color();
+            use_default_colors();
+            self.color_depth = ColorDepth::from_color_count(COLORS());
+            self.color_pairs = ColorPairs::new(COLOR_PAIRS());
+        }
+
+        #[cfg(unix)]
+        Self::disable_flow_control();
+    }
+
+    fn shutdown(&mut self) {
+        #[cfg(unix)]
+        Self::set_motion_tracking(false);
+        endwin();
+    }
+
+    fn draw_cell(&mut self, position: Vector2Int, cell: Cell) {
+        self.put_cell(position, cell);
+    }
+
+    fn clear(&mut self) {
+        self.window.clear();
+        self.reset_screen();
+    }
+
+    fn present(&mut self) {
+        self.redraw_recycled();
+        self.window.refresh();
+    }
+
+    fn poll_input(&mut self) -> Option<InputEvent> {
+        if let Some(event) = self.pending_events.pop_front() {
+            return Some(event);
+        }
+        #[cfg(unix)]
+        if let Some(event) = self.read_extended_key() {
+            return Some(event);
+        }
+        return match self.window.getch()? {
+            pancurses::Input::KeyMouse => match getmouse() {
+                Ok(mouse_event) => {
+                    let position = Vector2Int {
+                        x: mouse_event.x,
+                        y: mouse_event.y,
+                    };
+                    let mut events = Self::to_mouse_events(mouse_event.bstate)
+                        .into_iter()
+                        .map(|event| InputEvent::Mouse(event, position));
+                    let first = events.next().unwrap_or(InputEvent::Other);
+                    self.pending_events.extend(events);
+                    Some(first)
+                }
+                Err(_) => Some(InputEvent::Other),
+            },
+            pancurses::Input::Character('\x1b') => Some(self.read_escape()),
+            pancurses::Input::Character(char) => Some(InputEvent::from_char(char)),
+            pancurses::Input::KeyResize => {
+                // PDCurses only picks up the new terminal size when asked to, ncurses already has
+                #[cfg(windows)]
+                resize_term(0, 0);
+                Some(InputEvent::Resize(self.get_size()))
+            }
+            input => match Self::to_key(input) {
+                Some((special_key, modifiers)) => Some(Self::to_key_event(special_key, modifiers)),
+                None => Some(InputEvent::Other),
+            },
+        };
+    }
+
+    fn get_size(&self) -> Vector2Int {
+        return Vector2Int {
+            x: self.window.get_max_x(),
+            y: self.window.get_max_y(),
+        };
+    }
+
+    fn set_size(&mut self, size: Vector2Int) {
+        resize_term(size.y, size.x);
+        self.reset_screen();
+    }
+
+    fn set_title(&mut self, title: &str) {
+        set_title(title);
+    }
+
+    /// Curses colors are palette based, so even on terminals supporting 24-bit colors
+    /// cells are reduced to 256 colors. Use `AnsiBackend::terminal` for 24-bit colors
+    fn get_color_depth(&self) -> ColorDepth {
+        return self.color_depth.min(ColorDepth::Ansi256);
+    }
+
+    fn set_cursor_mode(&mut self, mode: CursorMode) {
+        curs_set(mode as i32);
+    }
+
+    fn flash(&self) {
+        flash();
+    }
+
+    fn beep(&self) {
+        beep();
+    }
+}
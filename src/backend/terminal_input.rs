@@ -0,0 +1,276 @@
+use super::escape_sequence;
+use crate::{
+    input::{InputEvent, Key, Modifiers, MouseEvent},
+    types::vector2int::Vector2Int,
+};
+
+// Report button presses, drags and motion in the SGR format, which has no coordinate limit
+pub(crate) const MOUSE_TRACKING_ON: &str = "\x1b[?1003h\x1b[?1006h";
+pub(crate) const MOUSE_TRACKING_OFF: &str = "\x1b[?1006l\x1b[?1003l";
+
+/// Reads keys, mouse reports and size changes straight from the terminal, for
+/// backends which do not go through curses
+pub(crate) struct TerminalInput {
+    // Terminal settings to restore when input is disabled
+    saved_termios: Option<libc::termios>,
+    bytes: Vec<u8>,
+    terminal_size: Option<Vector2Int>,
+}
+
+impl TerminalInput {
+    /// Default constructor
+    pub(crate) fn new() -> Self {
+        return Self {
+            saved_termios: None,
+            bytes: Vec::new(),
+            terminal_size: None,
+        };
+    }
+
+    /// Put the terminal in non-blocking mode without echo, line buffering or flow control
+    pub(crate) fn enable(&mut self) {
+        unsafe {
+            let mut termios: libc::termios = std::mem::zeroed();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut termios) != 0 {
+                return;
+            }
+            self.saved_termios = Some(termios);
+            termios.c_lflag &= !(libc::ICANON | libc::ECHO);
+            termios.c_iflag &= !(libc::IXON | libc::ICRNL);
+            termios.c_cc[libc::VMIN] = 0;
+            termios.c_cc[libc::VTIME] = 0;
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &termios);
+        }
+        self.terminal_size = get_terminal_size();
+    }
+
+    /// Restore the terminal settings
+    pub(crate) fn disable(&mut self) {
+        if let Some(termios) = self.saved_termios.take() {
+            unsafe {
+                libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &termios);
+            }
+        }
+    }
+
+    /// Return the next pending input event, if any
+    pub(crate) fn poll(&mut self) -> Option<InputEvent> {
+        if self.bytes.is_empty() {
+            self.read();
+        }
+        if let Some((event, length)) = parse(&self.bytes) {
+            self.bytes.drain(..length);
+            return Some(event);
+        }
+
+        // Nothing typed, report the terminal size when it changed
+        let size = get_terminal_size();
+        if size.is_some() && size != self.terminal_size {
+            self.terminal_size = size;
+            return size.map(InputEvent::Resize);
+        }
+        return None;
+    }
+
+    // Read every byte available without waiting
+    fn read(&mut self) {
+        let mut buffer = [0u8; 256];
+        loop {
+            let count = unsafe {
+                libc::read(
+                    libc::STDIN_FILENO,
+                    buffer.as_mut_ptr() as *mut libc::c_void,
+                    buffer.len(),
+                )
+            };
+            if count <= 0 {
+                return;
+            }
+            self.bytes.extend_from_slice(&buffer[..count as usize]);
+        }
+    }
+}
+
+/// Get the terminal size in cells
+fn get_terminal_size() -> Option<Vector2Int> {
+    unsafe {
+        let mut size: libc::winsize = std::mem::zeroed();
+        if libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) != 0 || size.ws_col == 0 {
+            return None;
+        }
+        return Some(Vector2Int {
+            x: size.ws_col as i32,
+            y: size.ws_row as i32,
+        });
+    }
+}
+
+/// Decode the first input event of bytes read from a terminal, with the number of
+/// bytes it took. Escape sequences are expected to be read whole, a lone escape
+/// being the Escape key
+pub(crate) fn parse(bytes: &[u8]) -> Option<(InputEvent, usize)> {
+    let first = *bytes.first()?;
+    if first != 0x1b {
+        return Some(parse_char(bytes));
+    }
+
+    let (event, length) = match bytes.get(1) {
+        None => return Some((InputEvent::from_char('\x1b'), 1)),
+        Some(b'[') | Some(b'O') => parse_sequence(&bytes[1..]),
+        // Terminals send Alt combinations as the key preceded by an escape
+        Some(_) => {
+            let (event, length) = parse_char(&bytes[1..]);
+            (event.with_modifiers(Modifiers::ALT), length)
+        }
+    };
+    return Some((event, length + 1));
+}
+
+/// Decode a UTF-8 character
+fn parse_char(bytes: &[u8]) -> (InputEvent, usize) {
+    let length = match bytes[0] {
+        0xf0..=0xf7 => 4,
+        0xe0..=0xef => 3,
+        0xc0..=0xdf => 2,
+        _ => 1,
+    };
+    return match bytes
+        .get(..length)
+        .and_then(|bytes| std::str::from_utf8(bytes).ok())
+    {
+        Some(text) => (InputEvent::from_char(text.chars().next().unwrap()), length),
+        None => (InputEvent::Other, 1),
+    };
+}
+
+/// Decode a CSI or SS3 sequence given without its escape
+fn parse_sequence(bytes: &[u8]) -> (InputEvent, usize) {
+    let introducer = bytes[0] as char;
+    if introducer == '[' && bytes.get(1) == Some(&b'<') {
+        return parse_mouse(bytes);
+    }
+
+    // CSI sequences end with a character between '@' and '~', besides the Linux
+    // console's "[[", SS3 sequences with the first character which is not a digit
+    let mut sequence = introducer.to_string();
+    let mut length = 1;
+    for byte in bytes[1..].iter() {
+        length += 1;
+        let char = *byte as char;
+        sequence.push(char);
+        let is_final = if introducer == 'O' {
+            !char.is_ascii_digit()
+        } else {
+            ('@'..='~').contains(&char) && sequence != "[["
+        };
+        if is_final {
+            break;
+        }
+    }
+
+    // Nothing after the introducer, Alt+[ or Alt+O was pressed
+    if length == 1 {
+        return (
+            InputEvent::Modified(Key::Char(introducer), Modifiers::ALT),
+            1,
+        );
+    }
+    let event = match escape_sequence::decode(&sequence) {
+        Some((special_key, modifiers)) if modifiers.is_empty() => InputEvent::Special(special_key),
+        Some((special_key, modifiers)) => {
+            InputEvent::Modified(Key::Special(special_key), modifiers)
+        }
+        None => InputEvent::Other,
+    };
+    return (event, length);
+}
+
+/// Decode an SGR mouse report given without its escape, e.g. `[<0;10;5M` for the
+/// left button going down at column 10 and row 5
+fn parse_mouse(bytes: &[u8]) -> (InputEvent, usize) {
+    let end = match bytes.iter().position(|byte| *byte == b'M' || *byte == b'm') {
+        Some(end) => end,
+        None => return (InputEvent::Other, bytes.len()),
+    };
+    let parameters: Vec<i32> = String::from_utf8_lossy(&bytes[2..end])
+        .split(';')
+        .filter_map(|parameter| parameter.parse().ok())
+        .collect();
+    if parameters.len() != 3 {
+        return (InputEvent::Other, end + 1);
+    }
+
+    // Low bits give the button, higher ones flag motion and the wheel
+    let (code, is_release) = (parameters[0], bytes[end] == b'm');
+    let mouse_event = if code & 64 != 0 {
+        if code & 1 == 0 {
+            MouseEvent::WheelUp
+        } else {
+            MouseEvent::WheelDown
+        }
+    } else if code & 32 != 0 {
+        MouseEvent::Moved
+    } else {
+        match (code & 3, is_release) {
+            (0, false) => MouseEvent::LeftMouseDown,
+            (0, true) => MouseEvent::LeftMouseUp,
+            (1, false) => MouseEvent::MiddleMouseDown,
+            (1, true) => MouseEvent::MiddleMouseUp,
+            (2, false) => MouseEvent::RightMouseDown,
+            (2, true) => MouseEvent::RightMouseUp,
+            _ => return (InputEvent::Other, end + 1),
+        }
+    };
+    let position = Vector2Int {
+        x: parameters[1] - 1,
+        y: parameters[2] - 1,
+    };
+    return (InputEvent::Mouse(mouse_event, position), end + 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::SpecialKey;
+
+    #[test]
+    fn parses_characters_and_alt_prefix() {
+        assert!(parse(b"a") == Some((InputEvent::Character('a'), 1)));
+        assert!(parse("é!".as_bytes()) == Some((InputEvent::Character('é'), 2)));
+        assert!(parse(b"\x1b") == Some((InputEvent::Special(SpecialKey::Escape), 1)));
+        assert!(parse(b"\x1bx") == Some((InputEvent::Modified(Key::Char('x'), Modifiers::ALT), 2)));
+        assert!(
+            parse(b"\x1b\x01")
+                == Some((
+                    InputEvent::Modified(Key::Char('a'), Modifiers::CTRL | Modifiers::ALT),
+                    2
+                ))
+        );
+        assert!(parse(b"").is_none());
+    }
+
+    #[test]
+    fn parses_escape_sequences_one_at_a_time() {
+        let bytes = b"\x1b[1;5A\x1bOPq";
+        assert!(
+            parse(bytes)
+                == Some((
+                    InputEvent::Modified(Key::Special(SpecialKey::Up), Modifiers::CTRL),
+                    6
+                ))
+        );
+        assert!(parse(&bytes[6..]) == Some((InputEvent::Special(SpecialKey::F1), 3)));
+        assert!(parse(&bytes[9..]) == Some((InputEvent::Character('q'), 1)));
+        assert!(parse(b"\x1b[99~") == Some((InputEvent::Other, 5)));
+    }
+
+    #[test]
+    fn parses_sgr_mouse_reports() {
+        let down = InputEvent::Mouse(MouseEvent::LeftMouseDown, Vector2Int { x: 9, y: 4 });
+        assert!(parse(b"\x1b[<0;10;5M") == Some((down, 10)));
+        let up = InputEvent::Mouse(MouseEvent::RightMouseUp, Vector2Int { x: 0, y: 0 });
+        assert!(parse(b"\x1b[<2;1;1m") == Some((up, 9)));
+        let wheel = InputEvent::Mouse(MouseEvent::WheelDown, Vector2Int { x: 2, y: 2 });
+        assert!(parse(b"\x1b[<65;3;3M") == Some((wheel, 10)));
+    }
+}
@@ -0,0 +1,71 @@
+use std::any::Any;
+
+/// Type-erased storage of one component type, indexed by entity index
+pub trait ComponentStorage {
+    /// Check if an entity has a component in this storage
+    fn contains(&self, index: usize) -> bool;
+
+    /// Drop the component of an entity if it has one
+    fn remove(&mut self, index: usize);
+
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+/// Components of type T, one slot per entity index
+pub struct Storage<T> {
+    components: Vec<Option<T>>,
+}
+
+impl<T: 'static> Storage<T> {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self {
+            components: Vec::new(),
+        };
+    }
+
+    /// Get component of an entity
+    pub fn get(&self, index: usize) -> Option<&T> {
+        return self.components.get(index).and_then(|slot| slot.as_ref());
+    }
+
+    /// Get component of an entity to modify it
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        return self
+            .components
+            .get_mut(index)
+            .and_then(|slot| slot.as_mut());
+    }
+
+    /// Set component of an entity, returning the one it replaced
+    pub fn insert(&mut self, index: usize, component: T) -> Option<T> {
+        if index >= self.components.len() {
+            self.components.resize_with(index + 1, || None);
+        }
+        return self.components[index].replace(component);
+    }
+
+    /// Take component of an entity out of the storage
+    pub fn take(&mut self, index: usize) -> Option<T> {
+        return self.components.get_mut(index).and_then(|slot| slot.take());
+    }
+}
+
+impl<T: 'static> ComponentStorage for Storage<T> {
+    fn contains(&self, index: usize) -> bool {
+        return self.get(index).is_some();
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.take(index);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        return self;
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        return self;
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// A handle to an entity living in a World
+///
+/// Indices of despawned entities are reused, the generation tells an old handle
+/// apart from the entity that took its place
+pub struct Entity {
+    index: u32,
+    generation: u32,
+}
+
+impl fmt::Display for Entity {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}v{}", self.index, self.generation)
+    }
+}
+
+impl Entity {
+    /// Default constructor
+    pub(crate) fn new(index: u32, generation: u32) -> Self {
+        return Self { index, generation };
+    }
+
+    /// Get entity index in its World
+    pub fn get_index(&self) -> u32 {
+        return self.index;
+    }
+
+    /// Get how many times the entity index has been reused
+    pub fn get_generation(&self) -> u32 {
+        return self.generation;
+    }
+}
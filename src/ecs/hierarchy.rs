@@ -0,0 +1,116 @@
+use super::{entity::Entity, world::World};
+use crate::entities::game_object::GameObject;
+use crate::types::vector2int::Vector2Int;
+
+// Parent chains deeper than this are assumed to be cycles and cut off
+const MAX_HIERARCHY_DEPTH: usize = 64;
+
+// Parents of GameObject components, a chain ending at an entity without a
+// GameObject or which has been despawned
+impl World {
+    /// Get the position of an entity's GameObject in the world, through all of its parents
+    pub fn get_world_position(&self, entity: Entity) -> Option<Vector2Int> {
+        let game_object = self.get_component::<GameObject>(entity)?;
+        return Some(game_object.get_position() + self.get_parent_position(game_object));
+    }
+
+    /// Move an entity's GameObject to a new world position, whatever its parents'
+    /// positions are. Returns false if the entity has no GameObject
+    pub fn move_to_world(&mut self, entity: Entity, new_position: Vector2Int) -> bool {
+        let parent_position = match self.get_component::<GameObject>(entity) {
+            Some(game_object) => game_object
+                .get_parent()
+                .and_then(|parent| self.get_world_position(parent))
+                .unwrap_or_else(Vector2Int::zero),
+            None => return false,
+        };
+        let game_object = self.get_component_mut::<GameObject>(entity).unwrap();
+        game_object.move_to(new_position - parent_position);
+        return true;
+    }
+
+    /// Check if an entity's GameObject and all of its parents are visible, entities
+    /// without a GameObject always are
+    pub fn is_visible_in_hierarchy(&self, entity: Entity) -> bool {
+        return match self.get_component::<GameObject>(entity) {
+            Some(game_object) => self.is_visible_with_parents(game_object),
+            None => true,
+        };
+    }
+
+    /// Get entities whose GameObject has this entity as parent
+    pub fn get_children(&self, entity: Entity) -> Vec<Entity> {
+        return self
+            .query::<(GameObject,)>()
+            .into_iter()
+            .filter(|child| {
+                self.get_component::<GameObject>(*child)
+                    .unwrap()
+                    .get_parent()
+                    == Some(entity)
+            })
+            .collect();
+    }
+
+    /// Place a sprite's own GameObject in the world for drawing
+    ///
+    /// With a GameObject component on the entity, the sprite's position, layer and
+    /// z-index are offsets from the component's and both have to be visible. Without
+    /// one, the sprite's own parent places it
+    pub(crate) fn place_game_object(&self, entity: Entity, game_object: &GameObject) -> GameObject {
+        let mut placed = *game_object;
+        let mut position = game_object.get_position();
+        // The entity's GameObject stands in for the sprite's parent
+        let parent = match self.get_component::<GameObject>(entity) {
+            Some(entity_object) => {
+                position += entity_object.get_position();
+                placed.set_layer(entity_object.get_layer() + game_object.get_layer());
+                placed.set_z_index(entity_object.get_z_index() + game_object.get_z_index());
+                entity_object
+            }
+            None => game_object,
+        };
+        let is_visible = game_object.get_visible() && self.is_visible_with_parents(parent);
+
+        placed.clear_parent();
+        // Bypass static objects refusing to move
+        let is_static = placed.get_static();
+        placed.set_static(false);
+        placed.move_to(position + self.get_parent_position(parent));
+        placed.set_static(is_static);
+        placed.set_visible(is_visible);
+        return placed;
+    }
+
+    // Get the world position of a GameObject's parent, zero without one
+    fn get_parent_position(&self, game_object: &GameObject) -> Vector2Int {
+        let mut position = Vector2Int::zero();
+        let mut parent = game_object.get_parent();
+        for _ in 0..MAX_HIERARCHY_DEPTH {
+            match parent.and_then(|parent| self.get_component::<GameObject>(parent)) {
+                Some(object) => {
+                    position += object.get_position();
+                    parent = object.get_parent();
+                }
+                None => break,
+            }
+        }
+        return position;
+    }
+
+    // Check if a GameObject and all of its parents are visible
+    fn is_visible_with_parents(&self, game_object: &GameObject) -> bool {
+        if !game_object.get_visible() {
+            return false;
+        }
+        let mut parent = game_object.get_parent();
+        for _ in 0..MAX_HIERARCHY_DEPTH {
+            match parent.and_then(|parent| self.get_component::<GameObject>(parent)) {
+                Some(object) if !object.get_visible() => return false,
+                Some(object) => parent = object.get_parent(),
+                None => break,
+            }
+        }
+        return true;
+    }
+}
@@ -0,0 +1,6 @@
+pub(crate) mod component_storage;
+pub mod entity;
+mod hierarchy;
+pub mod query;
+pub mod schedule;
+pub mod world;
@@ -0,0 +1,24 @@
+use super::{entity::Entity, world::World};
+
+/// A set of component types entities are filtered by, written as a tuple
+///
+/// e.g. `world.query::<(GameObject, CharObject)>()` or `world.query::<(TextObject,)>()`
+pub trait Query {
+    /// Check if an entity has every component of the query
+    fn matches(world: &World, entity: Entity) -> bool;
+}
+
+macro_rules! impl_query {
+    ($($component:ident),+) => {
+        impl<$($component: 'static),+> Query for ($($component,)+) {
+            fn matches(world: &World, entity: Entity) -> bool {
+                return $(world.has_component::<$component>(entity))&&+;
+            }
+        }
+    };
+}
+
+impl_query!(A);
+impl_query!(A, B);
+impl_query!(A, B, C);
+impl_query!(A, B, C, D);
@@ -0,0 +1,84 @@
+use crate::app::App;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// When a system runs within a frame
+pub enum Stage {
+    /// After every user-defined fixed update, possibly several times a frame
+    FixedUpdate,
+    /// After the user-defined update
+    Update,
+    /// After the user-defined render, before queued objects are drawn
+    Render,
+}
+
+/// A function run every frame, usually going through entities of `app.world`
+pub type System = Box<dyn FnMut(&mut App)>;
+
+/// Systems of every stage, run in the order they were added
+pub struct Schedule {
+    fixed_update_systems: Vec<System>,
+    update_systems: Vec<System>,
+    render_systems: Vec<System>,
+}
+
+impl Schedule {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self {
+            fixed_update_systems: Vec::new(),
+            update_systems: Vec::new(),
+            render_systems: Vec::new(),
+        };
+    }
+
+    /// Add a system to a stage
+    pub fn add_system(&mut self, stage: Stage, system: System) {
+        self.get_systems_mut(stage).push(system);
+    }
+
+    /// Get number of systems in a stage
+    pub fn len(&self, stage: Stage) -> usize {
+        return match stage {
+            Stage::FixedUpdate => self.fixed_update_systems.len(),
+            Stage::Update => self.update_systems.len(),
+            Stage::Render => self.render_systems.len(),
+        };
+    }
+
+    /// Check if there is no system in any stage
+    pub fn is_empty(&self) -> bool {
+        return self.fixed_update_systems.is_empty()
+            && self.update_systems.is_empty()
+            && self.render_systems.is_empty();
+    }
+
+    /// Remove all systems
+    pub fn clear(&mut self) {
+        self.fixed_update_systems.clear();
+        self.update_systems.clear();
+        self.render_systems.clear();
+    }
+
+    /// Run every system of a stage
+    pub fn run(&mut self, stage: Stage, app: &mut App) {
+        for system in self.get_systems_mut(stage).iter_mut() {
+            system(app);
+        }
+    }
+
+    /// Move systems of another schedule after this schedule's systems
+    pub fn append(&mut self, other: &mut Schedule) {
+        self.fixed_update_systems
+            .append(&mut other.fixed_update_systems);
+        self.update_systems.append(&mut other.update_systems);
+        self.render_systems.append(&mut other.render_systems);
+    }
+
+    fn get_systems_mut(&mut self, stage: Stage) -> &mut Vec<System> {
+        return match stage {
+            Stage::FixedUpdate => &mut self.fixed_update_systems,
+            Stage::Update => &mut self.update_systems,
+            Stage::Render => &mut self.render_systems,
+        };
+    }
+}
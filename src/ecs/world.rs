@@ -0,0 +1,172 @@
+use super::{
+    component_storage::{ComponentStorage, Storage},
+    entity::Entity,
+    query::Query,
+};
+use std::{any::TypeId, collections::HashMap};
+
+/// Container of every entity and their components
+///
+/// Any `'static` type can be a component, lunar itself renders entities having a
+/// CharObject, an AnimatedObject or a TextObject, placed by their GameObject component
+/// if they have one
+pub struct World {
+    generations: Vec<u32>,
+    alive: Vec<bool>,
+    free_indices: Vec<u32>,
+    storages: HashMap<TypeId, Box<dyn ComponentStorage>>,
+}
+
+impl World {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self {
+            generations: Vec::new(),
+            alive: Vec::new(),
+            free_indices: Vec::new(),
+            storages: HashMap::new(),
+        };
+    }
+
+    /// Create a new entity without any component
+    pub fn spawn(&mut self) -> Entity {
+        if let Some(index) = self.free_indices.pop() {
+            self.alive[index as usize] = true;
+            return Entity::new(index, self.generations[index as usize]);
+        }
+        self.generations.push(0);
+        self.alive.push(true);
+        return Entity::new(self.generations.len() as u32 - 1, 0);
+    }
+
+    /// Remove an entity and all of its components, returning whether it was alive
+    pub fn despawn(&mut self, entity: Entity) -> bool {
+        if !self.is_alive(entity) {
+            return false;
+        }
+        let index = entity.get_index() as usize;
+        for storage in self.storages.values_mut() {
+            storage.remove(index);
+        }
+        self.alive[index] = false;
+        self.generations[index] = self.generations[index].wrapping_add(1);
+        self.free_indices.push(entity.get_index());
+        return true;
+    }
+
+    /// Remove every entity
+    pub fn clear(&mut self) {
+        for entity in self.get_entities() {
+            self.despawn(entity);
+        }
+    }
+
+    /// Check if an entity has not been despawned
+    pub fn is_alive(&self, entity: Entity) -> bool {
+        let index = entity.get_index() as usize;
+        return index < self.alive.len()
+            && self.alive[index]
+            && self.generations[index] == entity.get_generation();
+    }
+
+    /// Get number of entities alive
+    pub fn len(&self) -> usize {
+        return self.alive.len() - self.free_indices.len();
+    }
+
+    /// Check if there is no entity alive
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Get every entity alive
+    pub fn get_entities(&self) -> Vec<Entity> {
+        return (0..self.alive.len())
+            .filter(|index| self.alive[*index])
+            .map(|index| Entity::new(index as u32, self.generations[index]))
+            .collect();
+    }
+
+    /// Get entities having every component of a query, e.g. `(GameObject, CharObject)`
+    ///
+    /// The result is a list of handles, so components can be modified while going through it
+    pub fn query<Q: Query>(&self) -> Vec<Entity> {
+        return self
+            .get_entities()
+            .into_iter()
+            .filter(|entity| Q::matches(self, *entity))
+            .collect();
+    }
+
+    /// Add a component to an entity, replacing the one of the same type it had
+    ///
+    /// Returns false if the entity is not alive
+    pub fn add_component<T: 'static>(&mut self, entity: Entity, component: T) -> bool {
+        if !self.is_alive(entity) {
+            return false;
+        }
+        self.storages
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(Storage::<T>::new()))
+            .as_any_mut()
+            .downcast_mut::<Storage<T>>()
+            .expect("component storage has the wrong type")
+            .insert(entity.get_index() as usize, component);
+        return true;
+    }
+
+    /// Remove a component from an entity, returning it
+    pub fn remove_component<T: 'static>(&mut self, entity: Entity) -> Option<T> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+        return self
+            .get_storage_mut::<T>()?
+            .take(entity.get_index() as usize);
+    }
+
+    /// Check if an entity has a component
+    pub fn has_component<T: 'static>(&self, entity: Entity) -> bool {
+        return self.is_alive(entity)
+            && match self.storages.get(&TypeId::of::<T>()) {
+                Some(storage) => storage.contains(entity.get_index() as usize),
+                None => false,
+            };
+    }
+
+    /// Get a component of an entity
+    pub fn get_component<T: 'static>(&self, entity: Entity) -> Option<&T> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+        return self.get_storage::<T>()?.get(entity.get_index() as usize);
+    }
+
+    /// Get a component of an entity to modify it
+    pub fn get_component_mut<T: 'static>(&mut self, entity: Entity) -> Option<&mut T> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+        return self
+            .get_storage_mut::<T>()?
+            .get_mut(entity.get_index() as usize);
+    }
+
+    /// Get storage of a component type if any entity ever had one
+    fn get_storage<T: 'static>(&self) -> Option<&Storage<T>> {
+        return self
+            .storages
+            .get(&TypeId::of::<T>())?
+            .as_any()
+            .downcast_ref::<Storage<T>>();
+    }
+
+    /// Get storage of a component type to modify it
+    fn get_storage_mut<T: 'static>(&mut self) -> Option<&mut Storage<T>> {
+        return self
+            .storages
+            .get_mut(&TypeId::of::<T>())?
+            .as_any_mut()
+            .downcast_mut::<Storage<T>>();
+    }
+}
@@ -0,0 +1,302 @@
+use super::char_object::{CharMap, CharObject};
+use crate::app::App;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// How a clip plays once it reaches its last frame
+pub enum PlaybackMode {
+    /// Start again from the first frame
+    Loop,
+    /// Stay on the last frame
+    Once,
+    /// Play backwards to the first frame, then forwards again
+    PingPong,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// Something that happened while an animation played
+pub enum AnimationEvent {
+    /// A clip played once reached its last frame
+    Finished(String),
+    /// A looping or ping-pong clip went back to its first frame
+    Looped(String),
+}
+
+#[derive(Copy, Clone)]
+/// A char map shown for a number of seconds
+pub struct AnimationFrame {
+    pub char_map: CharMap,
+    pub duration: f32,
+}
+
+#[derive(Clone)]
+/// A named sequence of frames, e.g. idle, walk or explode
+pub struct AnimationClip {
+    frames: Vec<AnimationFrame>,
+    mode: PlaybackMode,
+}
+
+impl AnimationClip {
+    /// Default constructor, a clip without frames
+    pub fn new(mode: PlaybackMode) -> Self {
+        return Self {
+            frames: Vec::new(),
+            mode,
+        };
+    }
+
+    /// Add a frame shown for a number of seconds
+    pub fn add_frame(mut self, char_map: CharMap, duration: f32) -> AnimationClip {
+        self.frames.push(AnimationFrame { char_map, duration });
+        return self;
+    }
+
+    /// Get clip frames
+    pub fn get_frames(&self) -> &[AnimationFrame] {
+        return &self.frames;
+    }
+
+    /// Get how the clip plays once it reaches its last frame
+    pub fn get_mode(&self) -> PlaybackMode {
+        return self.mode;
+    }
+
+    /// Get seconds the clip takes to play from its first frame to its last
+    pub fn get_duration(&self) -> f32 {
+        return self.frames.iter().map(|frame| frame.duration).sum();
+    }
+}
+
+/// A CharObject showing frames of named clips one after the other
+///
+/// As an entity component next to a GameObject, it is played and rendered by the App itself
+#[derive(Clone)]
+pub struct AnimatedObject {
+    sprite: CharObject,
+    clips: HashMap<String, AnimationClip>,
+    current_clip: Option<String>,
+    current_frame: usize,
+    frame_time: f32,
+    is_reversed: bool,
+    is_playing: bool,
+    events: Vec<AnimationEvent>,
+}
+
+impl AnimatedObject {
+    /// Default constructor, the sprite gives position, colors and style to every frame
+    pub fn new(sprite: CharObject) -> Self {
+        return Self {
+            sprite,
+            clips: HashMap::new(),
+            current_clip: None,
+            current_frame: 0,
+            frame_time: 0.0,
+            is_reversed: false,
+            is_playing: false,
+            events: Vec::new(),
+        };
+    }
+
+    /// Add a named clip, replacing the clip of the same name
+    pub fn add_clip(mut self, name: &str, clip: AnimationClip) -> AnimatedObject {
+        // Replacing the playing clip starts it over, its frames may have changed
+        if self.current_clip.as_deref() == Some(name) {
+            self.current_frame = 0;
+            self.frame_time = 0.0;
+            self.is_reversed = false;
+        }
+        self.clips.insert(name.to_string(), clip);
+        return self;
+    }
+
+    /// Get a clip by name
+    pub fn get_clip(&self, name: &str) -> Option<&AnimationClip> {
+        return self.clips.get(name);
+    }
+
+    /// Start playing a clip from its first frame, unless it is already playing
+    ///
+    /// Returns false if there is no clip with this name
+    pub fn play(&mut self, name: &str) -> bool {
+        if self.is_playing && self.current_clip.as_deref() == Some(name) {
+            return true;
+        }
+        return self.restart(name);
+    }
+
+    /// Start playing a clip from its first frame, even if it is already playing
+    pub fn restart(&mut self, name: &str) -> bool {
+        if !self.clips.contains_key(name) {
+            return false;
+        }
+        self.current_clip = Some(name.to_string());
+        self.current_frame = 0;
+        self.frame_time = 0.0;
+        self.is_reversed = false;
+        self.is_playing = true;
+        return true;
+    }
+
+    /// Stop on the current frame
+    pub fn pause(&mut self) {
+        self.is_playing = false;
+    }
+
+    /// Go on playing from the current frame
+    pub fn resume(&mut self) {
+        self.is_playing = self.current_clip.is_some();
+    }
+
+    /// Check if a clip is playing
+    pub fn is_playing(&self) -> bool {
+        return self.is_playing;
+    }
+
+    /// Get name of the current clip
+    pub fn get_clip_name(&self) -> Option<&str> {
+        return self.current_clip.as_deref();
+    }
+
+    /// Get index of the frame shown in the current clip
+    pub fn get_frame_index(&self) -> usize {
+        return self.current_frame;
+    }
+
+    /// Get the sprite every frame is drawn with
+    pub fn get_sprite(&self) -> CharObject {
+        return self.sprite;
+    }
+
+    /// Get the sprite every frame is drawn with to move it or change its colors
+    pub fn get_sprite_mut(&mut self) -> &mut CharObject {
+        return &mut self.sprite;
+    }
+
+    /// Get the sprite showing the current frame
+    pub fn get_frame_sprite(&self) -> CharObject {
+        let mut sprite = self.sprite;
+        if let Some(frame) = self.get_current_frame() {
+            sprite.set_char_map(frame.char_map);
+        }
+        return sprite;
+    }
+
+    /// Take events that happened since the last time
+    pub fn take_events(&mut self) -> Vec<AnimationEvent> {
+        return std::mem::take(&mut self.events);
+    }
+
+    /// Advance the current clip by a number of seconds
+    pub fn update(&mut self, delta: f32) {
+        if !self.is_playing {
+            return;
+        }
+        let name = match self.current_clip.clone() {
+            Some(name) => name,
+            None => return,
+        };
+        let (frame_count, mode) = match self.clips.get(&name) {
+            Some(clip) if !clip.frames.is_empty() => (clip.frames.len(), clip.mode),
+            _ => return,
+        };
+
+        self.frame_time += delta;
+        loop {
+            let duration = self.clips[&name].frames[self.current_frame].duration;
+            if duration <= 0.0 {
+                // Frames without a duration are shown for a single update
+                self.frame_time = 0.0;
+                self.advance_frame(frame_count, mode, &name);
+                break;
+            }
+            if self.frame_time < duration {
+                break;
+            }
+            self.frame_time -= duration;
+            if !self.advance_frame(frame_count, mode, &name) {
+                self.frame_time = 0.0;
+                break;
+            }
+        }
+    }
+
+    /// Render object on App window, through the render queue
+    pub fn render(&self, app: &mut App) {
+        self.get_frame_sprite().render(app);
+    }
+
+    /// Go to the next frame, returning false once a clip played once is done
+    fn advance_frame(&mut self, frame_count: usize, mode: PlaybackMode, name: &str) -> bool {
+        let last = frame_count - 1;
+        match mode {
+            PlaybackMode::Once => {
+                if self.current_frame >= last {
+                    self.is_playing = false;
+                    self.events.push(AnimationEvent::Finished(name.to_string()));
+                    return false;
+                }
+                self.current_frame += 1;
+            }
+            PlaybackMode::Loop => {
+                if self.current_frame >= last {
+                    self.current_frame = 0;
+                    self.events.push(AnimationEvent::Looped(name.to_string()));
+                } else {
+                    self.current_frame += 1;
+                }
+            }
+            PlaybackMode::PingPong => {
+                if last == 0 {
+                    self.events.push(AnimationEvent::Looped(name.to_string()));
+                } else if self.is_reversed {
+                    self.current_frame -= 1;
+                    if self.current_frame == 0 {
+                        self.is_reversed = false;
+                        self.events.push(AnimationEvent::Looped(name.to_string()));
+                    }
+                } else {
+                    self.current_frame += 1;
+                    if self.current_frame == last {
+                        self.is_reversed = true;
+                    }
+                }
+            }
+        }
+        return true;
+    }
+
+    fn get_current_frame(&self) -> Option<&AnimationFrame> {
+        let clip = self.clips.get(self.current_clip.as_ref()?)?;
+        return clip.frames.get(self.current_frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, Array2};
+
+    fn clip(chars: &Array2<char>, frame_count: usize) -> AnimationClip {
+        let mut clip = AnimationClip::new(PlaybackMode::Loop);
+        for _ in 0..frame_count {
+            clip = clip.add_frame(CharMap::Chars(chars), 0.1);
+        }
+        return clip;
+    }
+
+    #[test]
+    fn replacing_playing_clip_starts_it_over() {
+        let chars = array![['x']];
+        let mut animated_object =
+            AnimatedObject::new(CharObject::new(&chars)).add_clip("walk", clip(&chars, 4));
+        animated_object.play("walk");
+        animated_object.update(0.35);
+        assert_eq!(animated_object.get_frame_index(), 3);
+
+        let mut animated_object = animated_object.add_clip("walk", clip(&chars, 2));
+        assert_eq!(animated_object.get_frame_index(), 0);
+        animated_object.update(0.15);
+        assert_eq!(animated_object.get_frame_index(), 1);
+        assert!(animated_object.is_playing());
+    }
+}
@@ -1,23 +1,180 @@
 use super::game_object::GameObject;
 use crate::app::App;
-use crate::types::vector2int::Vector2Int;
+use crate::assets::{
+    asset_manager::{AssetManager, Handle},
+    char_map_loader::{CharMapAsset, SpriteSheet},
+};
+use crate::ecs::entity::Entity;
+use crate::rendering::{cell::Cell, render_queue::Renderable, style::Style};
+use crate::types::{color32::Color32, vector2int::Vector2Int};
 use ndarray::Array2;
 
+// Asset a char map is read from
+#[derive(Copy, Clone)]
+enum AssetSource {
+    Map(Handle<CharMapAsset>),
+    Frame(Handle<SpriteSheet>, usize),
+}
+
+#[derive(Copy, Clone)]
+/// A char map asset or sprite sheet frame held by an AssetManager, with its size
+pub struct AssetCharMap {
+    source: AssetSource,
+    rows: usize,
+    columns: usize,
+}
+
+#[derive(Copy, Clone)]
+/// A 2D map of characters or colored cells
+///
+/// Pointer maps have to outlive the objects drawing them, asset maps stay valid as
+/// long as the App's AssetManager holding them
+pub enum CharMap {
+    Chars(*const Array2<char>),
+    Cells(*const Array2<Cell>),
+    Asset(AssetCharMap),
+}
+
+impl CharMap {
+    /// Constructor from a char map asset, None if the manager does not hold it
+    pub fn from_asset(assets: &AssetManager, handle: Handle<CharMapAsset>) -> Option<CharMap> {
+        let asset = assets.get(handle)?;
+        return Some(CharMap::Asset(AssetCharMap {
+            source: AssetSource::Map(handle),
+            rows: asset.get_height(),
+            columns: asset.get_width(),
+        }));
+    }
+
+    /// Constructor from a frame of a sprite sheet asset, None if the manager does not
+    /// hold the sheet or the sheet has no such frame
+    pub fn from_frame(
+        assets: &AssetManager,
+        handle: Handle<SpriteSheet>,
+        name: &str,
+    ) -> Option<CharMap> {
+        let sheet = assets.get(handle)?;
+        let index = sheet.get_names().iter().position(|frame| frame == name)?;
+        let frame = sheet.get_frame(name)?;
+        return Some(CharMap::Asset(AssetCharMap {
+            source: AssetSource::Frame(handle, index),
+            rows: frame.get_height(),
+            columns: frame.get_width(),
+        }));
+    }
+
+    /// Get map width
+    pub fn get_width(&self) -> usize {
+        return self.get_shape()[1];
+    }
+
+    /// Get map height
+    pub fn get_height(&self) -> usize {
+        return self.get_shape()[0];
+    }
+
+    /// Get cell at a row and column, plain characters have unset colors
+    ///
+    /// Asset maps are read from the manager holding them, None if it does not
+    pub fn get_cell(&self, assets: &AssetManager, y: usize, x: usize) -> Option<Cell> {
+        return self.resolve(assets)?.read_cell(y, x);
+    }
+
+    // Get the map of the asset an asset map points to, other maps as they are
+    fn resolve(&self, assets: &AssetManager) -> Option<CharMap> {
+        return match *self {
+            CharMap::Asset(asset_map) => match asset_map.source {
+                AssetSource::Map(handle) => Some(assets.get(handle)?.get_char_map()),
+                AssetSource::Frame(handle, index) => {
+                    let sheet = assets.get(handle)?;
+                    Some(
+                        sheet
+                            .get_frame(sheet.get_names().get(index)?)?
+                            .get_char_map(),
+                    )
+                }
+            },
+            char_map => Some(char_map),
+        };
+    }
+
+    // Read a cell of a pointer map
+    fn read_cell(&self, y: usize, x: usize) -> Option<Cell> {
+        return match *self {
+            CharMap::Chars(map) => Some(Cell::new(unsafe { map.as_ref().unwrap() }[[y, x]])),
+            CharMap::Cells(map) => Some(unsafe { map.as_ref().unwrap() }[[y, x]]),
+            CharMap::Asset(_) => None,
+        };
+    }
+
+    /// Get map shape as rows and columns
+    fn get_shape(&self) -> [usize; 2] {
+        let shape = match *self {
+            CharMap::Chars(map) => unsafe { map.as_ref().unwrap() }.dim(),
+            CharMap::Cells(map) => unsafe { map.as_ref().unwrap() }.dim(),
+            CharMap::Asset(asset_map) => (asset_map.rows, asset_map.columns),
+        };
+        return [shape.0, shape.1];
+    }
+}
+
 #[derive(Copy, Clone)]
 /// A 2D character-based object
+///
+/// As a component of an entity with a GameObject, its own position, layer and z-index
+/// are offsets from the entity's GameObject, and both have to be visible for it to be drawn
 pub struct CharObject {
     game_object: GameObject,
-    is_visible: bool,
-    char_map: *const Array2<char>,
+    foreground_color: Option<Color32>,
+    background_color: Option<Color32>,
+    style: Style,
+    char_map: CharMap,
+    transparent_char: Option<char>,
 }
 
 impl CharObject {
     /// Default constructor
     pub fn new(char_map: *const Array2<char>) -> Self {
+        return Self::from_char_map(CharMap::Chars(char_map));
+    }
+
+    /// Constructor from a map of colored cells
+    pub fn from_cells(cell_map: *const Array2<Cell>) -> Self {
+        return Self::from_char_map(CharMap::Cells(cell_map));
+    }
+
+    /// Constructor from a char map asset, drawn with its transparent character
+    ///
+    /// None if the manager does not hold the asset
+    pub fn from_asset(assets: &AssetManager, handle: Handle<CharMapAsset>) -> Option<Self> {
+        let mut char_object = Self::from_char_map(CharMap::from_asset(assets, handle)?);
+        char_object.set_transparent_char(assets.get(handle)?.get_transparent_char());
+        return Some(char_object);
+    }
+
+    /// Constructor from a frame of a sprite sheet asset, drawn with its transparent character
+    ///
+    /// None if the manager does not hold the sheet or the sheet has no such frame
+    pub fn from_frame(
+        assets: &AssetManager,
+        handle: Handle<SpriteSheet>,
+        name: &str,
+    ) -> Option<Self> {
+        let mut char_object = Self::from_char_map(CharMap::from_frame(assets, handle, name)?);
+        let frame = assets.get(handle)?.get_frame(name)?;
+        char_object.set_transparent_char(frame.get_transparent_char());
+        return Some(char_object);
+    }
+
+    /// Constructor from any char map
+    pub fn from_char_map(char_map: CharMap) -> Self {
         return Self {
             game_object: GameObject::new(),
-            is_visible: true,
+            foreground_color: None,
+            background_color: None,
+            style: Style::NONE,
             char_map,
+            transparent_char: None,
         };
     }
 
@@ -49,48 +206,188 @@ impl CharObject {
         return *self;
     }
 
+    /// Get object render layer
+    pub fn get_layer(&self) -> i32 {
+        return self.game_object.get_layer();
+    }
+
+    /// Set object render layer, higher layers are drawn on top
+    pub fn set_layer(&mut self, layer: i32) -> CharObject {
+        self.game_object.set_layer(layer);
+        return *self;
+    }
+
+    /// Get object z-index
+    pub fn get_z_index(&self) -> i32 {
+        return self.game_object.get_z_index();
+    }
+
+    /// Set object z-index, objects with higher z-index are drawn on top within a layer
+    pub fn set_z_index(&mut self, z_index: i32) -> CharObject {
+        self.game_object.set_z_index(z_index);
+        return *self;
+    }
+
+    /// Get object parent entity
+    pub fn get_parent(&self) -> Option<Entity> {
+        return self.game_object.get_parent();
+    }
+
+    /// Set object parent entity, position becomes relative to the parent once the
+    /// object is a component of the App's World, unless its entity has a GameObject
+    /// component which then places it instead
+    pub fn set_parent(&mut self, parent: Entity) -> CharObject {
+        self.game_object.set_parent(parent);
+        return *self;
+    }
+
+    /// Detach object from its parent
+    pub fn clear_parent(&mut self) -> CharObject {
+        self.game_object.clear_parent();
+        return *self;
+    }
+
     /// Get parent GameObject
     pub fn get_game_object(&self) -> GameObject {
         return self.game_object;
     }
 
+    /// Replace the GameObject placing this object
+    pub fn set_game_object(&mut self, game_object: GameObject) -> CharObject {
+        self.game_object = game_object;
+        return *self;
+    }
+
+    /// Set object foreground color, overriding the App's foreground color
+    pub fn set_foreground_color(&mut self, color: impl Into<Color32>) -> CharObject {
+        self.foreground_color = Some(color.into());
+        return *self;
+    }
+
+    /// Get object foreground color, None if the App's foreground color is used
+    pub fn get_foreground_color(&self) -> Option<Color32> {
+        return self.foreground_color;
+    }
+
+    /// Set object background color, overriding the App's background color
+    pub fn set_background_color(&mut self, color: impl Into<Color32>) -> CharObject {
+        self.background_color = Some(color.into());
+        return *self;
+    }
+
+    /// Get object background color, None if the App's background color is used
+    pub fn get_background_color(&self) -> Option<Color32> {
+        return self.background_color;
+    }
+
+    /// Set object text attributes, added to the attributes of every drawn cell
+    pub fn set_style(&mut self, style: Style) -> CharObject {
+        self.style = style;
+        return *self;
+    }
+
+    /// Get object text attributes
+    pub fn get_style(&self) -> Style {
+        return self.style;
+    }
+
     /// Return object visibility
     pub fn get_visible(&self) -> bool {
-        return self.is_visible;
+        return self.game_object.get_visible();
     }
 
-    /// Set object visibility
+    /// Set object visibility, hiding an object hides its children too
     pub fn set_visible(&mut self, value: bool) -> CharObject {
-        self.is_visible = value;
+        self.game_object.set_visible(value);
+        return *self;
+    }
+
+    /// Get the character left undrawn so what is underneath shows through
+    pub fn get_transparent_char(&self) -> Option<char> {
+        return self.transparent_char;
+    }
+
+    /// Set the character left undrawn, or None to draw every character
+    pub fn set_transparent_char(&mut self, transparent_char: Option<char>) -> CharObject {
+        self.transparent_char = transparent_char;
+        return *self;
+    }
+
+    /// Get object char map
+    pub fn get_char_map(&self) -> CharMap {
+        return self.char_map;
+    }
+
+    /// Set object char map
+    pub fn set_char_map(&mut self, char_map: CharMap) -> CharObject {
+        self.char_map = char_map;
         return *self;
     }
 
     /// Get char map width
     pub fn get_width(&self) -> usize {
-        let map = unsafe { self.char_map.as_ref().unwrap() };
-        return map.shape()[1];
+        return self.char_map.get_width();
     }
 
     /// Get char map height
     pub fn get_height(&self) -> usize {
-        let map = unsafe { self.char_map.as_ref().unwrap() };
-        return map.shape()[0];
+        return self.char_map.get_height();
     }
 
     /// Render object on App window
+    ///
+    /// Object is queued and drawn after the App's render step, ordered by layer and z-index
     pub fn render(&self, app: &mut App) {
-        if !self.is_visible {
+        app.submit(*self);
+    }
+}
+
+impl Renderable for CharObject {
+    fn get_game_object(&self) -> GameObject {
+        return self.game_object;
+    }
+
+    fn get_size(&self) -> Vector2Int {
+        return Vector2Int {
+            x: self.get_width() as i32,
+            y: self.get_height() as i32,
+        };
+    }
+
+    fn draw(&self, app: &mut App) {
+        if !self.game_object.get_visible() {
             return;
         }
 
-        // Render chars in char map
-        let map = unsafe { self.char_map.as_ref().unwrap() };
+        // Render cells in char map, unset cell colors fall back to object then App colors
+        let char_map = match self.char_map.resolve(&app.assets) {
+            Some(char_map) => char_map,
+            None => return,
+        };
+        let foreground = self
+            .foreground_color
+            .unwrap_or_else(|| app.get_foreground_color());
+        let background = self
+            .background_color
+            .unwrap_or_else(|| app.get_background_color());
         for y in 0..self.get_height() {
             for x in 0..self.get_width() {
-                app.window.mvaddch(
-                    self.get_position().y + y as i32,
-                    self.get_position().x + x as i32,
-                    map[[y, x]],
+                let mut cell = match char_map.read_cell(y, x) {
+                    Some(cell) => cell,
+                    None => return,
+                };
+                if Some(cell.glyph) == self.transparent_char {
+                    continue;
+                }
+                cell = cell.with_fallback(foreground, background);
+                cell.style |= self.style;
+                app.draw_cell(
+                    self.get_position()
+                        + Vector2Int {
+                            x: x as i32,
+                            y: y as i32,
+                        },
+                    cell,
                 );
             }
         }
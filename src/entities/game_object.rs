@@ -1,10 +1,19 @@
+use crate::ecs::entity::Entity;
 use crate::types::vector2int::Vector2Int;
 
 #[derive(Copy, Clone)]
 /// An object that exist in the game world
+///
+/// A GameObject component may have a parent entity, in which case its position is
+/// relative to the parent's world position and it is hidden whenever the parent is
+/// hidden, see `World::get_world_position`
 pub struct GameObject {
     is_static: bool,
+    is_visible: bool,
     position: Vector2Int,
+    parent: Option<Entity>,
+    layer: i32,
+    z_index: i32,
 }
 
 impl GameObject {
@@ -12,7 +21,11 @@ impl GameObject {
     pub fn new() -> Self {
         return Self {
             is_static: false,
+            is_visible: true,
             position: Vector2Int::zero(),
+            parent: None,
+            layer: 0,
+            z_index: 0,
         };
     }
 
@@ -27,7 +40,7 @@ impl GameObject {
         return *self;
     }
 
-    /// Get object position
+    /// Get object position, relative to the parent if there is one
     pub fn get_position(&self) -> Vector2Int {
         return self.position;
     }
@@ -49,4 +62,54 @@ impl GameObject {
         self.position.translate(delta);
         return *self;
     }
+
+    /// Get object render layer, higher layers are drawn on top
+    pub fn get_layer(&self) -> i32 {
+        return self.layer;
+    }
+
+    /// Set object render layer
+    pub fn set_layer(&mut self, layer: i32) -> GameObject {
+        self.layer = layer;
+        return *self;
+    }
+
+    /// Get object z-index, objects with higher z-index are drawn on top within a layer
+    pub fn get_z_index(&self) -> i32 {
+        return self.z_index;
+    }
+
+    /// Set object z-index
+    pub fn set_z_index(&mut self, z_index: i32) -> GameObject {
+        self.z_index = z_index;
+        return *self;
+    }
+
+    /// Get object parent entity
+    pub fn get_parent(&self) -> Option<Entity> {
+        return self.parent;
+    }
+
+    /// Set object parent entity, whose GameObject component the position becomes relative to
+    pub fn set_parent(&mut self, parent: Entity) -> GameObject {
+        self.parent = Some(parent);
+        return *self;
+    }
+
+    /// Detach object from its parent
+    pub fn clear_parent(&mut self) -> GameObject {
+        self.parent = None;
+        return *self;
+    }
+
+    /// Return object's own visibility
+    pub fn get_visible(&self) -> bool {
+        return self.is_visible;
+    }
+
+    /// Set object visibility, hiding an object hides its children too
+    pub fn set_visible(&mut self, value: bool) -> GameObject {
+        self.is_visible = value;
+        return *self;
+    }
 }
@@ -1,3 +1,4 @@
+pub mod animated_object;
 pub mod char_object;
 pub mod game_object;
 pub mod text_object;
@@ -1,22 +1,51 @@
 use super::game_object::GameObject;
 use crate::app::App;
-use crate::types::vector2int::Vector2Int;
+use crate::assets::asset_manager::{AssetManager, Handle};
+use crate::ecs::entity::Entity;
+use crate::rendering::{cell::Cell, render_queue::Renderable, style::Style};
+use crate::types::{color32::Color32, vector2int::Vector2Int};
+
+// Where the text of an object is read from
+#[derive(Copy, Clone)]
+enum TextSource {
+    Pointer(*const String),
+    // Text asset and its length in characters
+    Asset(Handle<String>, usize),
+}
 
 #[derive(Copy, Clone)]
 /// A 1D string-based object
+///
+/// As a component of an entity with a GameObject, its own position, layer and z-index
+/// are offsets from the entity's GameObject, and both have to be visible for it to be drawn
 pub struct TextObject {
     game_object: GameObject,
-    is_visible: bool,
-    text: *const String,
+    foreground_color: Option<Color32>,
+    background_color: Option<Color32>,
+    style: Style,
+    text: TextSource,
 }
 
 impl TextObject {
-    /// Default constructor
+    /// Default constructor, the text has to outlive the object
     pub fn new(text: *const String) -> Self {
+        return Self::from_source(TextSource::Pointer(text));
+    }
+
+    /// Constructor from a text asset, which stays valid as long as the App's
+    /// AssetManager holding it. None if the manager does not hold it
+    pub fn from_asset(assets: &AssetManager, handle: Handle<String>) -> Option<Self> {
+        let length = assets.get(handle)?.chars().count();
+        return Some(Self::from_source(TextSource::Asset(handle, length)));
+    }
+
+    fn from_source(text: TextSource) -> Self {
         return Self {
             game_object: GameObject::new(),
-            is_visible: true,
-            text
+            foreground_color: None,
+            background_color: None,
+            style: Style::NONE,
+            text,
         };
     }
 
@@ -48,30 +77,153 @@ impl TextObject {
         return *self;
     }
 
+    /// Get object render layer
+    pub fn get_layer(&self) -> i32 {
+        return self.game_object.get_layer();
+    }
+
+    /// Set object render layer, higher layers are drawn on top
+    pub fn set_layer(&mut self, layer: i32) -> TextObject {
+        self.game_object.set_layer(layer);
+        return *self;
+    }
+
+    /// Get object z-index
+    pub fn get_z_index(&self) -> i32 {
+        return self.game_object.get_z_index();
+    }
+
+    /// Set object z-index, objects with higher z-index are drawn on top within a layer
+    pub fn set_z_index(&mut self, z_index: i32) -> TextObject {
+        self.game_object.set_z_index(z_index);
+        return *self;
+    }
+
+    /// Get object parent entity
+    pub fn get_parent(&self) -> Option<Entity> {
+        return self.game_object.get_parent();
+    }
+
+    /// Set object parent entity, position becomes relative to the parent once the
+    /// object is a component of the App's World, unless its entity has a GameObject
+    /// component which then places it instead
+    pub fn set_parent(&mut self, parent: Entity) -> TextObject {
+        self.game_object.set_parent(parent);
+        return *self;
+    }
+
+    /// Detach object from its parent
+    pub fn clear_parent(&mut self) -> TextObject {
+        self.game_object.clear_parent();
+        return *self;
+    }
+
     /// Get parent GameObject
     pub fn get_game_object(&self) -> GameObject {
         return self.game_object;
     }
 
+    /// Replace the GameObject placing this object
+    pub fn set_game_object(&mut self, game_object: GameObject) -> TextObject {
+        self.game_object = game_object;
+        return *self;
+    }
+
+    /// Set object foreground color, overriding the App's foreground color
+    pub fn set_foreground_color(&mut self, color: impl Into<Color32>) -> TextObject {
+        self.foreground_color = Some(color.into());
+        return *self;
+    }
+
+    /// Get object foreground color, None if the App's foreground color is used
+    pub fn get_foreground_color(&self) -> Option<Color32> {
+        return self.foreground_color;
+    }
+
+    /// Set object background color, overriding the App's background color
+    pub fn set_background_color(&mut self, color: impl Into<Color32>) -> TextObject {
+        self.background_color = Some(color.into());
+        return *self;
+    }
+
+    /// Get object background color, None if the App's background color is used
+    pub fn get_background_color(&self) -> Option<Color32> {
+        return self.background_color;
+    }
+
+    /// Set object text attributes, added to the attributes of every drawn cell
+    pub fn set_style(&mut self, style: Style) -> TextObject {
+        self.style = style;
+        return *self;
+    }
+
+    /// Get object text attributes
+    pub fn get_style(&self) -> Style {
+        return self.style;
+    }
+
     /// Return object visibility
     pub fn get_visible(&self) -> bool {
-        return self.is_visible;
+        return self.game_object.get_visible();
     }
 
-    /// Set object visibility
+    /// Set object visibility, hiding an object hides its children too
     pub fn set_visible(&mut self, value: bool) -> TextObject {
-        self.is_visible = value;
+        self.game_object.set_visible(value);
         return *self;
     }
 
     /// Render object on App window
+    ///
+    /// Object is queued and drawn after the App's render step, ordered by layer and z-index
     pub fn render(&self, app: &mut App) {
-        if !self.is_visible {
+        app.submit(*self);
+    }
+}
+
+impl Renderable for TextObject {
+    fn get_game_object(&self) -> GameObject {
+        return self.game_object;
+    }
+
+    fn get_size(&self) -> Vector2Int {
+        let length = match self.text {
+            TextSource::Pointer(text) => unsafe { text.as_ref().unwrap() }.chars().count(),
+            TextSource::Asset(_, length) => length,
+        };
+        return Vector2Int {
+            x: length as i32,
+            y: 1,
+        };
+    }
+
+    fn draw(&self, app: &mut App) {
+        if !self.game_object.get_visible() {
             return;
         }
 
-        // Render string text
-        let text = unsafe { self.text.as_ref().unwrap() };
-        app.window.mvaddstr(self.get_position().y, self.get_position().x, text);
+        // Render string text, copied so the App can be drawn to
+        let text = match self.text {
+            TextSource::Pointer(text) => unsafe { text.as_ref().unwrap() }.clone(),
+            TextSource::Asset(handle, _) => match app.assets.get(handle) {
+                Some(text) => text.clone(),
+                None => return,
+            },
+        };
+        let foreground = self
+            .foreground_color
+            .unwrap_or_else(|| app.get_foreground_color());
+        let background = self
+            .background_color
+            .unwrap_or_else(|| app.get_background_color());
+        for (offset, glyph) in text.chars().enumerate() {
+            let cell = Cell::new(glyph)
+                .with_fallback(foreground, background)
+                .with_style(self.style);
+            app.draw_cell(
+                self.get_position() + Vector2Int::unit_x() * offset as i32,
+                cell,
+            );
+        }
     }
 }
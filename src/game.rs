@@ -0,0 +1,85 @@
+use crate::app::App;
+use crate::input::InputEvent;
+use crate::types::vector2int::Vector2Int;
+
+/// A game run by `App::run_game`, keeping its own state in `self`
+///
+/// Every hook has an empty default so only the ones needed have to be written
+pub trait Game {
+    /// Called once before the first frame
+    fn init(&mut self, _app: &mut App) {}
+
+    /// Called at a fixed rate set by `app.time`, zero or more times a frame before update
+    fn fixed_update(&mut self, _app: &mut App) {}
+
+    /// Called every frame, after input has been queried
+    fn update(&mut self, _app: &mut App) {}
+
+    /// Called every frame once the screen has been cleared
+    fn render(&mut self, _app: &mut App) {}
+
+    /// Called once after the last frame
+    fn exit(&mut self, _app: &mut App) {}
+
+    /// Called when the App size changes
+    fn on_resize(&mut self, _app: &mut App, _size: Vector2Int) {}
+
+    /// Called for every input event reported by the backend, before update
+    fn on_event(&mut self, _app: &mut App, _event: InputEvent) {}
+}
+
+/// Adapter running four closures as a Game, used by `App::run`
+pub struct ClosureGame<I, U, R, E>
+where
+    I: FnMut(&mut App),
+    U: FnMut(&mut App),
+    R: FnMut(&mut App),
+    E: FnMut(&mut App),
+{
+    init: I,
+    update: U,
+    render: R,
+    exit: E,
+}
+
+impl<I, U, R, E> ClosureGame<I, U, R, E>
+where
+    I: FnMut(&mut App),
+    U: FnMut(&mut App),
+    R: FnMut(&mut App),
+    E: FnMut(&mut App),
+{
+    /// Default constructor
+    pub fn new(init: I, update: U, render: R, exit: E) -> Self {
+        return Self {
+            init,
+            update,
+            render,
+            exit,
+        };
+    }
+}
+
+impl<I, U, R, E> Game for ClosureGame<I, U, R, E>
+where
+    I: FnMut(&mut App),
+    U: FnMut(&mut App),
+    R: FnMut(&mut App),
+    E: FnMut(&mut App),
+{
+    fn init(&mut self, app: &mut App) {
+        (self.init)(app);
+    }
+
+    fn update(&mut self, app: &mut App) {
+        (self.update)(app);
+    }
+
+    fn render(&mut self, app: &mut App) {
+        (self.render)(app);
+    }
+
+    fn exit(&mut self, app: &mut App) {
+        (self.exit)(app);
+    }
+}
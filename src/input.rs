@@ -1,22 +1,162 @@
-use pancurses::getmouse;
+use crate::types::vector2int::Vector2Int;
+use std::{collections::HashMap, ops};
 
-#[derive(PartialEq, Clone, Copy)]
+// Seconds a key stays held after its first event, longer than the usual delay
+// before a terminal starts repeating a held key
+pub const DEFAULT_HOLD_TIMEOUT: f32 = 0.6;
+// Seconds a key stays held between two repeat events, longer than the usual
+// terminal repeat interval
+pub const DEFAULT_REPEAT_TIMEOUT: f32 = 0.1;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// A key which does not type a character
 pub enum SpecialKey {
     None,
     Up,
     Down,
     Left,
     Right,
+
+    // Editing keys
+    Enter,
+    Escape,
+    Backspace,
+    Tab,
+    /// Shift + Tab
+    BackTab,
+    Insert,
+    Delete,
+
+    // Navigation keys
+    Home,
+    End,
+    PageUp,
+    PageDown,
+
+    // Function keys
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+
+    // Keypad keys, reported apart from the regular keys by some terminals
+    KeypadUpLeft,
+    KeypadUpRight,
+    KeypadCenter,
+    KeypadDownLeft,
+    KeypadDownRight,
+    KeypadEnter,
 }
 
-#[derive(PartialEq, Clone, Copy)]
+impl SpecialKey {
+    /// Get function key from its number, None if there is no such key
+    pub fn function(number: u8) -> Option<SpecialKey> {
+        const KEYS: [SpecialKey; 12] = [
+            SpecialKey::F1,
+            SpecialKey::F2,
+            SpecialKey::F3,
+            SpecialKey::F4,
+            SpecialKey::F5,
+            SpecialKey::F6,
+            SpecialKey::F7,
+            SpecialKey::F8,
+            SpecialKey::F9,
+            SpecialKey::F10,
+            SpecialKey::F11,
+            SpecialKey::F12,
+        ];
+        return KEYS.get((number as usize).wrapping_sub(1)).copied();
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// A set of modifier keys held with a key, combined with `|`
+pub struct Modifiers {
+    bits: u8,
+}
+
+// Logical operator overloading for type Modifiers
+impl ops::BitOr for Modifiers {
+    type Output = Modifiers;
+    fn bitor(self, rhs: Modifiers) -> Modifiers {
+        return Modifiers {
+            bits: self.bits | rhs.bits,
+        };
+    }
+}
+
+impl ops::BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, rhs: Modifiers) {
+        self.bits |= rhs.bits;
+    }
+}
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers { bits: 0 };
+    pub const SHIFT: Modifiers = Modifiers { bits: 1 };
+    /// Alt, also known as Meta
+    pub const ALT: Modifiers = Modifiers { bits: 1 << 1 };
+    pub const CTRL: Modifiers = Modifiers { bits: 1 << 2 };
+
+    /// Default constructor, no modifiers
+    pub fn new() -> Self {
+        return Modifiers::NONE;
+    }
+
+    /// Modifiers from the parameter of an xterm-style key sequence, e.g. 5 for Ctrl,
+    /// Meta is reported as Alt
+    pub fn from_xterm_parameter(parameter: u32) -> Modifiers {
+        let bits = parameter.saturating_sub(1);
+        let mut modifiers = Modifiers::NONE;
+        if bits & 1 != 0 {
+            modifiers |= Modifiers::SHIFT;
+        }
+        if bits & (2 | 8) != 0 {
+            modifiers |= Modifiers::ALT;
+        }
+        if bits & 4 != 0 {
+            modifiers |= Modifiers::CTRL;
+        }
+        return modifiers;
+    }
+
+    /// Check if no modifiers are set
+    pub fn is_empty(&self) -> bool {
+        return self.bits == 0;
+    }
+
+    /// Check if all modifiers of another set are set
+    pub fn contains(&self, other: Modifiers) -> bool {
+        return self.bits & other.bits == other.bits;
+    }
+
+    /// Set modifiers of another set
+    pub fn insert(&mut self, other: Modifiers) {
+        self.bits |= other.bits;
+    }
+
+    /// Unset modifiers of another set
+    pub fn remove(&mut self, other: Modifiers) {
+        self.bits &= !other.bits;
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum MouseButton {
     Left,
     Middle,
     Right,
 }
 
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum MouseEvent {
     None = 0,
     LeftMouseUp = 1,
@@ -31,13 +171,153 @@ pub enum MouseEvent {
     RightMouseDown = 2048,
     RightMouseClick = 4096,
     RightMouseDoubleClick = 8192,
+    /// The wheel was scrolled away from the user
+    WheelUp = 1 << 16,
+    /// The wheel was scrolled towards the user
+    WheelDown = 1 << 17,
+    /// The mouse moved to another cell, with or without a button down
+    Moved = 1 << 18,
+}
+
+impl MouseEvent {
+    /// Get the button of a button event, None for wheel and motion events
+    pub fn get_button(&self) -> Option<MouseButton> {
+        return match *self {
+            MouseEvent::LeftMouseUp
+            | MouseEvent::LeftMouseDown
+            | MouseEvent::LeftMouseClick
+            | MouseEvent::LeftMouseDoubleClick => Some(MouseButton::Left),
+            MouseEvent::MiddleMouseUp
+            | MouseEvent::MiddleMouseDown
+            | MouseEvent::MiddleMouseClick
+            | MouseEvent::MiddleMouseDoubleClick => Some(MouseButton::Middle),
+            MouseEvent::RightMouseUp
+            | MouseEvent::RightMouseDown
+            | MouseEvent::RightMouseClick
+            | MouseEvent::RightMouseDoubleClick => Some(MouseButton::Right),
+            _ => None,
+        };
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+/// A mouse drag, from the cell a button went down on to the cell the mouse is on,
+/// or was released on
+pub struct MouseDrag {
+    pub button: MouseButton,
+    pub start: Vector2Int,
+    pub end: Vector2Int,
+}
+
+#[derive(PartialEq, Clone, Copy)]
+/// A single input event reported by a backend
+pub enum InputEvent {
+    Character(char),
+    Special(SpecialKey),
+    /// A key pressed with modifier keys, e.g. Ctrl+S or Shift+Up
+    Modified(Key, Modifiers),
+    /// A mouse event, with the cell of the screen it happened on
+    Mouse(MouseEvent, Vector2Int),
+    /// The terminal was resized by the user, with its new size
+    Resize(Vector2Int),
+    Other,
+}
+
+impl InputEvent {
+    /// Event of a character read from the terminal, control characters sent by
+    /// keys such as Enter or Tab are reported as those keys, the others as Ctrl
+    /// combinations, e.g. Ctrl+S for '\x13'
+    pub fn from_char(char: char) -> InputEvent {
+        return match char {
+            '\n' | '\r' => InputEvent::Special(SpecialKey::Enter),
+            '\t' => InputEvent::Special(SpecialKey::Tab),
+            '\x08' | '\x7f' => InputEvent::Special(SpecialKey::Backspace),
+            '\x1b' => InputEvent::Special(SpecialKey::Escape),
+            '\x00' => InputEvent::Modified(Key::Char(' '), Modifiers::CTRL),
+            '\x01'..='\x1a' => {
+                let letter = (b'a' + char as u8 - 1) as char;
+                InputEvent::Modified(Key::Char(letter), Modifiers::CTRL)
+            }
+            '\x1c'..='\x1f' => {
+                let symbol = (char as u8 + 0x40) as char;
+                InputEvent::Modified(Key::Char(symbol), Modifiers::CTRL)
+            }
+            _ => InputEvent::Character(char),
+        };
+    }
+
+    /// Get the key of a key event and the modifiers held with it, None for other events
+    pub fn get_key(&self) -> Option<(Key, Modifiers)> {
+        return match *self {
+            InputEvent::Character(char) => Some((Key::Char(char), Modifiers::NONE)),
+            InputEvent::Special(special_key) => Some((Key::Special(special_key), Modifiers::NONE)),
+            InputEvent::Modified(key, modifiers) => Some((key, modifiers)),
+            _ => None,
+        };
+    }
+
+    /// Add modifiers to a key event, other events are returned unchanged
+    pub fn with_modifiers(self, modifiers: Modifiers) -> InputEvent {
+        return match self.get_key() {
+            Some((key, key_modifiers)) => InputEvent::Modified(key, key_modifiers | modifiers),
+            None => self,
+        };
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// A keyboard key, tracked by the Input manager
+pub enum Key {
+    Char(char),
+    Special(SpecialKey),
+}
+
+impl From<char> for Key {
+    fn from(char: char) -> Self {
+        return Key::Char(char);
+    }
+}
+
+impl From<SpecialKey> for Key {
+    fn from(special_key: SpecialKey) -> Self {
+        return Key::Special(special_key);
+    }
+}
+
+#[derive(Clone, Copy)]
+// Time since the last event of a held key, and the modifiers it came with
+struct HeldKey {
+    idle_time: f32,
+    is_repeating: bool,
+    modifiers: Modifiers,
 }
 
 // Input manager
+//
+// Terminals only report key presses, so a key is held from its first event until
+// no repeat event came for a timeout, then it is released. Modifier keys are never
+// reported on their own, only along with the keys they modify
 pub struct Input {
     char_key_down: char,
     special_key_down: SpecialKey,
     mouse_event: MouseEvent,
+    events: Vec<InputEvent>,
+
+    held_keys: HashMap<Key, HeldKey>,
+    pressed_keys: Vec<Key>,
+    released_keys: Vec<Key>,
+    hold_timeout: f32,
+    repeat_timeout: f32,
+
+    // Mouse state, buttons are reported going up so they need no timeout
+    mouse_position: Vector2Int,
+    has_mouse_moved: bool,
+    wheel_delta: i32,
+    held_buttons: Vec<MouseButton>,
+    pressed_buttons: Vec<MouseButton>,
+    released_buttons: Vec<MouseButton>,
+    drag: Option<MouseDrag>,
+    finished_drag: Option<MouseDrag>,
 }
 
 impl Input {
@@ -47,111 +327,501 @@ impl Input {
             char_key_down: ' ',
             special_key_down: SpecialKey::None,
             mouse_event: MouseEvent::None,
+            events: Vec::new(),
+
+            held_keys: HashMap::new(),
+            pressed_keys: Vec::new(),
+            released_keys: Vec::new(),
+            hold_timeout: DEFAULT_HOLD_TIMEOUT,
+            repeat_timeout: DEFAULT_REPEAT_TIMEOUT,
+
+            mouse_position: Vector2Int { x: 0, y: 0 },
+            has_mouse_moved: false,
+            wheel_delta: 0,
+            held_buttons: Vec::new(),
+            pressed_buttons: Vec::new(),
+            released_buttons: Vec::new(),
+            drag: None,
+            finished_drag: None,
         };
     }
 
-    // Query for input from App
-    pub fn query(&mut self, getch: Option<pancurses::Input>) {
-        match getch {
+    // Query for input from App, once per frame with every event polled since the
+    // last one and the seconds elapsed
+    pub fn query(&mut self, events: Vec<InputEvent>, delta: f32) {
+        self.pressed_keys.clear();
+        self.released_keys.clear();
+        self.has_mouse_moved = false;
+        self.wheel_delta = 0;
+        self.pressed_buttons.clear();
+        self.released_buttons.clear();
+        self.finished_drag = None;
+
+        let mut keys = Vec::new();
+        for event in events.iter() {
             // Handle mouse input
-            Some(pancurses::Input::KeyMouse) => {
-                if let Ok(mouse_event) = getmouse() {
-                    match mouse_event.bstate {
-                        1 => self.mouse_event = MouseEvent::LeftMouseUp,
-                        2 => self.mouse_event = MouseEvent::LeftMouseDown,
-                        4 => self.mouse_event = MouseEvent::LeftMouseClick,
-                        8 => self.mouse_event = MouseEvent::LeftMouseDoubleClick,
-                        32 => self.mouse_event = MouseEvent::MiddleMouseUp,
-                        64 => self.mouse_event = MouseEvent::MiddleMouseDown,
-                        128 => self.mouse_event = MouseEvent::MiddleMouseClick,
-                        256 => self.mouse_event = MouseEvent::MiddleMouseDoubleClick,
-                        1024 => self.mouse_event = MouseEvent::RightMouseUp,
-                        2048 => self.mouse_event = MouseEvent::RightMouseDown,
-                        4096 => self.mouse_event = MouseEvent::RightMouseClick,
-                        8192 => self.mouse_event = MouseEvent::RightMouseDoubleClick,
-                        _ => self.mouse_event = MouseEvent::None,
-                    }
+            if let InputEvent::Mouse(mouse_event, position) = *event {
+                self.query_mouse(mouse_event, position);
+            }
+            // Handle key input, with or without modifiers
+            if let Some((key, modifiers)) = event.get_key() {
+                match key {
+                    Key::Char(char) => self.char_key_down = char,
+                    Key::Special(special_key) => self.special_key_down = special_key,
                 }
+                keys.push((key, modifiers));
             }
-            // Handle character key input
-            Some(pancurses::Input::Character(char)) => {
-                self.char_key_down = char;
+        }
+        self.events = events;
+
+        // Release keys without a repeat event for too long
+        let (hold_timeout, repeat_timeout) = (self.hold_timeout, self.repeat_timeout);
+        let released_keys = &mut self.released_keys;
+        self.held_keys.retain(|held_key, held| {
+            if keys.iter().any(|(key, _)| key == held_key) {
+                return true;
             }
-            // Handle special key input
-            Some(pancurses::Input::KeyUp) => {
-                self.special_key_down = SpecialKey::Up;
+            held.idle_time += delta;
+            let timeout = if held.is_repeating {
+                repeat_timeout
+            } else {
+                hold_timeout
+            };
+            if held.idle_time > timeout {
+                released_keys.push(*held_key);
+                return false;
             }
-            Some(pancurses::Input::KeyDown) => {
-                self.special_key_down = SpecialKey::Down;
+            return true;
+        });
+        for released_key in self.released_keys.iter() {
+            match *released_key {
+                Key::Char(char) if char == self.char_key_down => self.char_key_down = ' ',
+                Key::Special(special_key) if special_key == self.special_key_down => {
+                    self.special_key_down = SpecialKey::None
+                }
+                _ => {}
             }
-            Some(pancurses::Input::KeyLeft) => {
-                self.special_key_down = SpecialKey::Left;
+        }
+
+        // A key is pressed on its first event, later repeats keep it held. Several
+        // events in the frame the key went down are quick taps, not a repeat
+        for (key, modifiers) in keys {
+            match self.held_keys.get_mut(&key) {
+                Some(held) => {
+                    held.idle_time = 0.0;
+                    held.is_repeating |= !self.pressed_keys.contains(&key);
+                    held.modifiers = modifiers;
+                }
+                None => {
+                    self.held_keys.insert(
+                        key,
+                        HeldKey {
+                            idle_time: 0.0,
+                            is_repeating: false,
+                            modifiers,
+                        },
+                    );
+                    self.pressed_keys.push(key);
+                }
             }
-            Some(pancurses::Input::KeyRight) => {
-                self.special_key_down = SpecialKey::Right;
+        }
+    }
+
+    // Update mouse state from a mouse event
+    fn query_mouse(&mut self, mouse_event: MouseEvent, position: Vector2Int) {
+        self.mouse_event = mouse_event;
+        if position != self.mouse_position {
+            self.has_mouse_moved = true;
+            self.mouse_position = position;
+        }
+        if let Some(drag) = self.drag.as_mut() {
+            drag.end = position;
+        }
+
+        match mouse_event {
+            MouseEvent::WheelUp => self.wheel_delta += 1,
+            MouseEvent::WheelDown => self.wheel_delta -= 1,
+            MouseEvent::LeftMouseDown
+            | MouseEvent::MiddleMouseDown
+            | MouseEvent::RightMouseDown => {
+                let button = mouse_event.get_button().unwrap();
+                if !self.held_buttons.contains(&button) {
+                    self.held_buttons.push(button);
+                    self.pressed_buttons.push(button);
+                }
+                // A drag starts on any button, until it goes up
+                if self.drag.is_none() {
+                    self.drag = Some(MouseDrag {
+                        button,
+                        start: position,
+                        end: position,
+                    });
+                }
             }
-            // Handle input up
-            Some(_key) => {
-                self.char_key_down = ' ';
-                self.special_key_down = SpecialKey::None;
+            MouseEvent::LeftMouseUp | MouseEvent::MiddleMouseUp | MouseEvent::RightMouseUp => {
+                let button = mouse_event.get_button().unwrap();
+                self.held_buttons.retain(|held| *held != button);
+                self.released_buttons.push(button);
+                if let Some(drag) = self.drag {
+                    if drag.button == button {
+                        self.drag = None;
+                        if drag.start != drag.end {
+                            self.finished_drag = Some(drag);
+                        }
+                    }
+                }
             }
-            None => {
-                self.char_key_down = ' ';
-                self.special_key_down = SpecialKey::None;
+            // Clicks go down and up at once
+            MouseEvent::None | MouseEvent::Moved => {}
+            _ => {
+                let button = mouse_event.get_button().unwrap();
+                self.pressed_buttons.push(button);
+                self.released_buttons.push(button);
             }
         }
     }
 
-    /// Get current character key down
+    /// Get every event received this frame, in the order they happened
+    pub fn get_events(&self) -> &[InputEvent] {
+        return &self.events;
+    }
+
+    /// Check if a key went down this frame, whatever modifiers it came with
+    pub fn is_pressed(&self, key: impl Into<Key>) -> bool {
+        return self.pressed_keys.contains(&key.into());
+    }
+
+    /// Check if a key is down, since this frame or an earlier one, whatever
+    /// modifiers it came with
+    ///
+    /// Terminals do not report key releases, so a single tap stays held for the hold timeout
+    pub fn is_held(&self, key: impl Into<Key>) -> bool {
+        return self.held_keys.contains_key(&key.into());
+    }
+
+    /// Check if a key is held long enough for the terminal to repeat it, a single tap
+    /// never is, e.g. `is_pressed(key) || is_repeating(key)` acts once per tap and
+    /// keeps acting while the key is held down
+    pub fn is_repeating(&self, key: impl Into<Key>) -> bool {
+        return self
+            .held_keys
+            .get(&key.into())
+            .is_some_and(|held| held.is_repeating);
+    }
+
+    /// Check if a key went down this frame with exactly these modifiers, e.g.
+    /// `is_pressed_with('s', Modifiers::CTRL)` for Ctrl+S
+    pub fn is_pressed_with(&self, key: impl Into<Key>, modifiers: Modifiers) -> bool {
+        let key = key.into();
+        return self.is_pressed(key) && self.get_modifiers(key) == Some(modifiers);
+    }
+
+    /// Check if a key is down with exactly these modifiers
+    pub fn is_held_with(&self, key: impl Into<Key>, modifiers: Modifiers) -> bool {
+        return self.get_modifiers(key) == Some(modifiers);
+    }
+
+    /// Get the modifiers of the last event of a key which is down, None if it is not
+    pub fn get_modifiers(&self, key: impl Into<Key>) -> Option<Modifiers> {
+        return self.held_keys.get(&key.into()).map(|held| held.modifiers);
+    }
+
+    /// Check if Ctrl is held along with any key down
+    pub fn is_ctrl_down(&self) -> bool {
+        return self.is_modifier_down(Modifiers::CTRL);
+    }
+
+    /// Check if Alt is held along with any key down
+    pub fn is_alt_down(&self) -> bool {
+        return self.is_modifier_down(Modifiers::ALT);
+    }
+
+    /// Check if Shift is held along with any key down, typed capitals do not count
+    pub fn is_shift_down(&self) -> bool {
+        return self.is_modifier_down(Modifiers::SHIFT);
+    }
+
+    /// Check if some modifiers are held along with any key down
+    fn is_modifier_down(&self, modifiers: Modifiers) -> bool {
+        return self
+            .held_keys
+            .values()
+            .any(|held| held.modifiers.contains(modifiers));
+    }
+
+    /// Check if a key went up this frame
+    pub fn is_released(&self, key: impl Into<Key>) -> bool {
+        return self.released_keys.contains(&key.into());
+    }
+
+    /// Get keys which went down this frame
+    pub fn get_pressed_keys(&self) -> &[Key] {
+        return &self.pressed_keys;
+    }
+
+    /// Get keys which went up this frame
+    pub fn get_released_keys(&self) -> &[Key] {
+        return &self.released_keys;
+    }
+
+    /// Get all keys currently down
+    pub fn get_held_keys(&self) -> Vec<Key> {
+        return self.held_keys.keys().copied().collect();
+    }
+
+    /// Get seconds a key stays held after its first event
+    pub fn get_hold_timeout(&self) -> f32 {
+        return self.hold_timeout;
+    }
+
+    /// Set seconds a key stays held after its first event, it should be longer than
+    /// the terminal's delay before repeating a held key
+    pub fn set_hold_timeout(&mut self, hold_timeout: f32) {
+        self.hold_timeout = hold_timeout;
+    }
+
+    /// Get seconds a key stays held between two repeat events
+    pub fn get_repeat_timeout(&self) -> f32 {
+        return self.repeat_timeout;
+    }
+
+    /// Set seconds a key stays held between two repeat events, it should be longer
+    /// than the terminal's key repeat interval
+    pub fn set_repeat_timeout(&mut self, repeat_timeout: f32) {
+        self.repeat_timeout = repeat_timeout;
+    }
+
+    /// Get the last character key down, ' ' if none is held
     pub fn get_char_key_down(&self) -> char {
         return self.char_key_down;
     }
 
     /// Check if a character key is down
     pub fn is_char_key_down(&self, key: char) -> bool {
-        return key == self.char_key_down;
+        return self.is_held(key);
     }
 
     /// Check if a character is not down
     pub fn is_char_key_up(&self, key: char) -> bool {
-        return key != self.char_key_down;
+        return !self.is_held(key);
     }
 
-    /// Get current special key down
+    /// Get the last special key down, SpecialKey::None if none is held
     pub fn get_special_key_down(&self) -> SpecialKey {
         return self.special_key_down;
     }
 
     /// Check if a special key is down
     pub fn is_special_key_down(&self, key: SpecialKey) -> bool {
-        return key == self.special_key_down;
+        return self.is_held(key);
     }
 
     /// Check if a special key is not down
     pub fn is_special_key_up(&self, key: SpecialKey) -> bool {
-        return key != self.special_key_down;
+        return !self.is_held(key);
     }
 
-    /// Get current mouse event
+    /// Get last mouse event
     pub fn get_mouse_event(&self) -> MouseEvent {
         return self.mouse_event;
     }
 
+    /// Get the screen cell the mouse was last reported on
+    pub fn get_mouse_position(&self) -> Vector2Int {
+        return self.mouse_position;
+    }
+
+    /// Check if the mouse moved to another cell this frame
+    pub fn has_mouse_moved(&self) -> bool {
+        return self.has_mouse_moved;
+    }
+
+    /// Get wheel steps scrolled this frame, positive away from the user
+    pub fn get_wheel_delta(&self) -> i32 {
+        return self.wheel_delta;
+    }
+
+    /// Check if a mouse button went down this frame, clicks included
+    pub fn is_mouse_pressed(&self, mouse_button: MouseButton) -> bool {
+        return self.pressed_buttons.contains(&mouse_button);
+    }
+
+    /// Check if a mouse button went up this frame, clicks included
+    pub fn is_mouse_released(&self, mouse_button: MouseButton) -> bool {
+        return self.released_buttons.contains(&mouse_button);
+    }
+
     /// Check if a mouse button is down
     pub fn is_mouse_down(&self, mouse_button: MouseButton) -> bool {
-        match mouse_button {
-            MouseButton::Left => self.mouse_event == MouseEvent::LeftMouseDown,
-            MouseButton::Middle => self.mouse_event == MouseEvent::MiddleMouseDown,
-            MouseButton::Right => self.mouse_event == MouseEvent::RightMouseDown,
-        }
+        return self.held_buttons.contains(&mouse_button);
     }
 
     /// Check if a mouse button is not down
     pub fn is_mouse_up(&self, mouse_button: MouseButton) -> bool {
-        match mouse_button {
-            MouseButton::Left => self.mouse_event == MouseEvent::LeftMouseUp,
-            MouseButton::Middle => self.mouse_event == MouseEvent::MiddleMouseUp,
-            MouseButton::Right => self.mouse_event == MouseEvent::RightMouseUp,
+        return !self.is_mouse_down(mouse_button);
+    }
+
+    /// Get the drag in progress, from where its button went down to the mouse
+    /// position, None if no button is down
+    pub fn get_drag(&self) -> Option<MouseDrag> {
+        return self.drag;
+    }
+
+    /// Check if the mouse left the cell a button went down on, without the button going up
+    pub fn is_dragging(&self) -> bool {
+        return self.drag.is_some_and(|drag| drag.start != drag.end);
+    }
+
+    /// Get the drag which ended this frame, from where its button went down to
+    /// where it went up
+    pub fn get_finished_drag(&self) -> Option<MouseDrag> {
+        return self.finished_drag;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_xterm_modifier_parameters() {
+        assert_eq!(Modifiers::from_xterm_parameter(1), Modifiers::NONE);
+        assert_eq!(Modifiers::from_xterm_parameter(2), Modifiers::SHIFT);
+        assert_eq!(Modifiers::from_xterm_parameter(3), Modifiers::ALT);
+        assert_eq!(Modifiers::from_xterm_parameter(5), Modifiers::CTRL);
+        assert_eq!(
+            Modifiers::from_xterm_parameter(8),
+            Modifiers::SHIFT | Modifiers::ALT | Modifiers::CTRL
+        );
+        // Meta counts as Alt
+        assert_eq!(Modifiers::from_xterm_parameter(9), Modifiers::ALT);
+        assert_eq!(Modifiers::from_xterm_parameter(0), Modifiers::NONE);
+    }
+
+    #[test]
+    fn decodes_control_characters() {
+        assert!(
+            InputEvent::from_char('\x01') == InputEvent::Modified(Key::Char('a'), Modifiers::CTRL)
+        );
+        assert!(
+            InputEvent::from_char('\x13') == InputEvent::Modified(Key::Char('s'), Modifiers::CTRL)
+        );
+        assert!(
+            InputEvent::from_char('\x00') == InputEvent::Modified(Key::Char(' '), Modifiers::CTRL)
+        );
+        assert!(
+            InputEvent::from_char('\x1c') == InputEvent::Modified(Key::Char('\\'), Modifiers::CTRL)
+        );
+        assert!(InputEvent::from_char('\r') == InputEvent::Special(SpecialKey::Enter));
+        assert!(InputEvent::from_char('\t') == InputEvent::Special(SpecialKey::Tab));
+        assert!(InputEvent::from_char('\x7f') == InputEvent::Special(SpecialKey::Backspace));
+        assert!(InputEvent::from_char('A') == InputEvent::Character('A'));
+    }
+
+    #[test]
+    fn adds_modifiers_to_key_events() {
+        let event = InputEvent::Character('x').with_modifiers(Modifiers::ALT);
+        assert!(event == InputEvent::Modified(Key::Char('x'), Modifiers::ALT));
+        let event = event.with_modifiers(Modifiers::CTRL);
+        assert_eq!(
+            event.get_key(),
+            Some((Key::Char('x'), Modifiers::ALT | Modifiers::CTRL))
+        );
+        assert!(InputEvent::Other.with_modifiers(Modifiers::ALT) == InputEvent::Other);
+    }
+
+    // Frames of a twentieth of a second, below the repeat timeout
+    const FRAME: f32 = 0.05;
+
+    #[test]
+    fn key_is_pressed_on_first_frame_only() {
+        let mut input = Input::new();
+        input.query(vec![InputEvent::Character('w')], FRAME);
+        assert!(input.is_pressed('w'));
+        assert!(input.is_held('w'));
+        assert!(!input.is_repeating('w'));
+        assert!(!input.is_released('w'));
+        assert_eq!(input.get_pressed_keys(), &[Key::Char('w')]);
+
+        input.query(Vec::new(), FRAME);
+        assert!(!input.is_pressed('w'));
+        assert!(input.is_held('w'));
+        assert!(input.get_pressed_keys().is_empty());
+    }
+
+    #[test]
+    fn tapped_key_is_released_after_hold_timeout() {
+        let mut input = Input::new();
+        input.set_hold_timeout(0.2);
+        input.query(vec![InputEvent::Special(SpecialKey::Up)], FRAME);
+        for _ in 0..4 {
+            input.query(Vec::new(), FRAME);
+            assert!(input.is_held(SpecialKey::Up));
+            assert!(!input.is_released(SpecialKey::Up));
         }
+
+        input.query(Vec::new(), FRAME);
+        assert!(input.is_released(SpecialKey::Up));
+        assert!(!input.is_held(SpecialKey::Up));
+        assert_eq!(input.get_special_key_down(), SpecialKey::None);
+
+        input.query(Vec::new(), FRAME);
+        assert!(!input.is_released(SpecialKey::Up));
+    }
+
+    #[test]
+    fn repeated_key_stays_held_then_releases_after_repeat_timeout() {
+        let mut input = Input::new();
+        input.query(vec![InputEvent::Character('s')], FRAME);
+        input.query(vec![InputEvent::Character('s')], FRAME);
+        assert!(!input.is_pressed('s'));
+        assert!(input.is_repeating('s'));
+
+        // Still repeating within the repeat timeout
+        input.query(Vec::new(), FRAME);
+        assert!(input.is_held('s'));
+        input.query(vec![InputEvent::Character('s')], FRAME);
+        assert!(input.is_held('s'));
+
+        input.query(Vec::new(), FRAME);
+        input.query(Vec::new(), FRAME);
+        input.query(Vec::new(), FRAME);
+        assert!(input.is_released('s'));
+        assert!(!input.is_repeating('s'));
+
+        // The next event is a new press
+        input.query(vec![InputEvent::Character('s')], FRAME);
+        assert!(input.is_pressed('s'));
+        assert!(!input.is_repeating('s'));
+    }
+
+    #[test]
+    fn quick_taps_in_one_frame_are_not_a_repeat() {
+        let mut input = Input::new();
+        let a = InputEvent::Character('a');
+        input.query(vec![a, a], FRAME);
+        assert!(input.is_pressed('a'));
+        assert!(!input.is_repeating('a'));
+        assert_eq!(input.get_pressed_keys(), &[Key::Char('a')]);
+
+        input.query(vec![a], FRAME);
+        assert!(input.is_repeating('a'));
+    }
+
+    #[test]
+    fn keys_keep_the_modifiers_of_their_last_event() {
+        let mut input = Input::new();
+        let ctrl_s = InputEvent::Modified(Key::Char('s'), Modifiers::CTRL);
+        input.query(vec![ctrl_s, InputEvent::Character('a')], FRAME);
+        assert!(input.is_pressed('s'));
+        assert!(input.is_pressed_with('s', Modifiers::CTRL));
+        assert!(!input.is_pressed_with('a', Modifiers::CTRL));
+        assert!(input.is_ctrl_down());
+        assert!(!input.is_alt_down());
+
+        input.query(vec![InputEvent::Character('s')], FRAME);
+        assert!(input.is_held_with('s', Modifiers::NONE));
+        assert!(!input.is_ctrl_down());
+        assert_eq!(input.get_modifiers('x'), None);
     }
 }
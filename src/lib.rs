@@ -1,5 +1,23 @@
+// The codebase favours explicit returns and `new()` constructors over `Default`
+#![allow(
+    clippy::needless_return,
+    clippy::new_without_default,
+    clippy::unused_unit,
+    clippy::unnecessary_cast,
+    clippy::partialeq_ne_impl
+)]
+
 pub mod app;
+pub mod assets;
+pub mod backend;
 pub mod debug;
+pub mod ecs;
 pub mod entities;
+pub mod game;
 pub mod input;
+pub mod rendering;
+pub mod scene;
+pub mod scheduler;
+pub mod time;
+pub mod tweening;
 pub mod types;
@@ -0,0 +1,126 @@
+use crate::types::{rect_int::RectInt, vector2int::Vector2Int};
+
+#[derive(Copy, Clone)]
+/// A view into the game world, positioned by its top left corner in world coordinates
+pub struct Camera {
+    position: Vector2Int,
+    target: Option<Vector2Int>,
+    bounds: Option<RectInt>,
+    dead_zone: Vector2Int,
+}
+
+impl Camera {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self {
+            position: Vector2Int::zero(),
+            target: None,
+            bounds: None,
+            dead_zone: Vector2Int::zero(),
+        };
+    }
+
+    /// Get camera position (world coordinates of the view's top left corner)
+    pub fn get_position(&self) -> Vector2Int {
+        return self.position;
+    }
+
+    /// Move camera to a new position
+    pub fn move_to(&mut self, new_position: Vector2Int) -> Camera {
+        self.position = new_position;
+        return *self;
+    }
+
+    /// Move camera by a vector
+    pub fn move_by(&mut self, delta: Vector2Int) -> Camera {
+        self.position.translate(delta);
+        return *self;
+    }
+
+    /// Get world position the camera is following
+    pub fn get_target(&self) -> Option<Vector2Int> {
+        return self.target;
+    }
+
+    /// Set world position the camera follows, usually updated every frame
+    pub fn set_target(&mut self, target: Vector2Int) -> Camera {
+        self.target = Some(target);
+        return *self;
+    }
+
+    /// Stop following a target
+    pub fn clear_target(&mut self) -> Camera {
+        self.target = None;
+        return *self;
+    }
+
+    /// Get world area the camera view is kept inside of
+    pub fn get_bounds(&self) -> Option<RectInt> {
+        return self.bounds;
+    }
+
+    /// Set world area the camera view is kept inside of
+    pub fn set_bounds(&mut self, bounds: RectInt) -> Camera {
+        self.bounds = Some(bounds);
+        return *self;
+    }
+
+    /// Let the camera view move freely
+    pub fn clear_bounds(&mut self) -> Camera {
+        self.bounds = None;
+        return *self;
+    }
+
+    /// Get dead-zone size
+    pub fn get_dead_zone(&self) -> Vector2Int {
+        return self.dead_zone;
+    }
+
+    /// Set size of the area around the view center the target can move in without
+    /// the camera moving
+    pub fn set_dead_zone(&mut self, dead_zone: Vector2Int) -> Camera {
+        self.dead_zone = dead_zone;
+        return *self;
+    }
+
+    /// Get world area seen by a view of a size
+    pub fn get_view(&self, view_size: Vector2Int) -> RectInt {
+        return RectInt::new(self.position, view_size);
+    }
+
+    /// Follow target and stay inside bounds, for a view of a size
+    pub fn update(&mut self, view_size: Vector2Int) {
+        if let Some(target) = self.target {
+            // Keep target inside the dead-zone centered in the view, at least one cell wide
+            let size = Vector2Int {
+                x: self.dead_zone.x.max(1),
+                y: self.dead_zone.y.max(1),
+            };
+            let dead_zone = RectInt::new(self.position + (view_size - size) / 2, size);
+            if target.x < dead_zone.left() {
+                self.position.x -= dead_zone.left() - target.x;
+            } else if target.x >= dead_zone.right() {
+                self.position.x += target.x - dead_zone.right() + 1;
+            }
+            if target.y < dead_zone.top() {
+                self.position.y -= dead_zone.top() - target.y;
+            } else if target.y >= dead_zone.bottom() {
+                self.position.y += target.y - dead_zone.bottom() + 1;
+            }
+        }
+
+        if let Some(bounds) = self.bounds {
+            // Clamp view inside bounds, views larger than bounds stick to the top left
+            self.position.x = self
+                .position
+                .x
+                .min(bounds.right() - view_size.x)
+                .max(bounds.left());
+            self.position.y = self
+                .position
+                .y
+                .min(bounds.bottom() - view_size.y)
+                .max(bounds.top());
+        }
+    }
+}
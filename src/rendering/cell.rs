@@ -0,0 +1,100 @@
+use super::style::Style;
+use crate::types::color32::Color32;
+use ndarray::{Array2, Zip};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// A single character cell with its own colors
+///
+/// Transparent colors are left unset: on screen they show the terminal's default color,
+/// and in an object's cell map they fall back to the object's (or App's) colors
+pub struct Cell {
+    pub glyph: char,
+    pub foreground: Color32,
+    pub background: Color32,
+    pub style: Style,
+}
+
+impl Cell {
+    /// Default constructor, a glyph with unset colors
+    pub fn new(glyph: char) -> Self {
+        return Self {
+            glyph,
+            foreground: Color32::transparent(),
+            background: Color32::transparent(),
+            style: Style::NONE,
+        };
+    }
+
+    /// Empty cell
+    pub fn blank() -> Self {
+        return Self::new(' ');
+    }
+
+    /// Copy of cell with a different foreground color
+    pub fn with_foreground(mut self, color: impl Into<Color32>) -> Cell {
+        self.foreground = color.into();
+        return self;
+    }
+
+    /// Copy of cell with a different background color
+    pub fn with_background(mut self, color: impl Into<Color32>) -> Cell {
+        self.background = color.into();
+        return self;
+    }
+
+    /// Copy of cell with different text attributes
+    pub fn with_style(mut self, style: Style) -> Cell {
+        self.style = style;
+        return self;
+    }
+
+    /// Copy of cell with unset colors replaced by other colors
+    pub fn with_fallback(mut self, foreground: Color32, background: Color32) -> Cell {
+        if self.foreground.is_transparent() {
+            self.foreground = foreground;
+        }
+        if self.background.is_transparent() {
+            self.background = background;
+        }
+        return self;
+    }
+
+    /// Build a cell map from a char map, every cell using the same colors
+    pub fn from_char_map(
+        char_map: &Array2<char>,
+        foreground: impl Into<Color32>,
+        background: impl Into<Color32>,
+    ) -> Array2<Cell> {
+        let foreground = foreground.into();
+        let background = background.into();
+        return char_map.map(|glyph| Cell {
+            glyph: *glyph,
+            foreground,
+            background,
+            style: Style::NONE,
+        });
+    }
+
+    /// Build a cell map from a char map and a foreground color map of the same shape
+    ///
+    /// Returns None if the maps have different shapes
+    pub fn from_color_map(
+        char_map: &Array2<char>,
+        color_map: &Array2<Color32>,
+    ) -> Option<Array2<Cell>> {
+        if char_map.shape() != color_map.shape() {
+            return None;
+        }
+        return Some(
+            Zip::from(char_map)
+                .and(color_map)
+                .map_collect(|glyph, color| Cell::new(*glyph).with_foreground(*color)),
+        );
+    }
+}
+
+impl From<char> for Cell {
+    fn from(glyph: char) -> Cell {
+        return Cell::new(glyph);
+    }
+}
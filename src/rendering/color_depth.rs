@@ -0,0 +1,39 @@
+use std::env;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+/// How many colors a terminal is able to display
+pub enum ColorDepth {
+    Ansi8,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Guess color depth of the current terminal from its environment variables
+    pub fn detect() -> Self {
+        let color_term = env::var("COLORTERM").unwrap_or_default().to_lowercase();
+        if color_term.contains("truecolor") || color_term.contains("24bit") {
+            return ColorDepth::TrueColor;
+        }
+
+        let term = env::var("TERM").unwrap_or_default().to_lowercase();
+        if term.contains("direct") || term.contains("truecolor") {
+            return ColorDepth::TrueColor;
+        }
+        if term.contains("256") {
+            return ColorDepth::Ansi256;
+        }
+        return ColorDepth::Ansi8;
+    }
+
+    /// Color depth able to display at least a number of colors
+    pub fn from_color_count(colors: i32) -> Self {
+        return if colors >= 1 << 24 {
+            ColorDepth::TrueColor
+        } else if colors >= 256 {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi8
+        };
+    }
+}
@@ -0,0 +1,161 @@
+use super::cell::Cell;
+use crate::types::vector2int::Vector2Int;
+
+#[derive(Clone)]
+/// A grid of cells that a frame is drawn into
+pub struct FrameBuffer {
+    size: Vector2Int,
+    cells: Vec<Cell>,
+}
+
+impl FrameBuffer {
+    /// Default constructor, every cell is set to the fill cell
+    pub fn new(size: Vector2Int, fill: Cell) -> Self {
+        let length = (size.x.max(0) * size.y.max(0)) as usize;
+        return Self {
+            size,
+            cells: vec![fill; length],
+        };
+    }
+
+    /// Get buffer size in cells
+    pub fn get_size(&self) -> Vector2Int {
+        return self.size;
+    }
+
+    /// Resize buffer, every cell is reset to the fill cell
+    pub fn resize(&mut self, size: Vector2Int, fill: Cell) {
+        *self = FrameBuffer::new(size, fill);
+    }
+
+    /// Check if a position is inside the buffer
+    pub fn contains(&self, position: Vector2Int) -> bool {
+        return position.x >= 0
+            && position.y >= 0
+            && position.x < self.size.x
+            && position.y < self.size.y;
+    }
+
+    /// Get a cell, or None if the position is outside the buffer
+    pub fn get(&self, position: Vector2Int) -> Option<Cell> {
+        if !self.contains(position) {
+            return None;
+        }
+        return Some(self.cells[self.index(position)]);
+    }
+
+    /// Set a cell, positions outside the buffer are ignored
+    pub fn set(&mut self, position: Vector2Int, cell: Cell) {
+        if !self.contains(position) {
+            return;
+        }
+        let index = self.index(position);
+        self.cells[index] = cell;
+    }
+
+    /// Set every cell to the fill cell
+    pub fn fill(&mut self, fill: Cell) {
+        for cell in self.cells.iter_mut() {
+            *cell = fill;
+        }
+    }
+
+    /// Get positions and cells of this buffer that differ from another buffer of the same size
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a FrameBuffer,
+    ) -> impl Iterator<Item = (Vector2Int, Cell)> + 'a {
+        let width = self.size.x;
+        return self
+            .cells
+            .iter()
+            .zip(other.cells.iter())
+            .enumerate()
+            .filter(|(_, (cell, other_cell))| cell != other_cell)
+            .map(move |(index, (cell, _))| {
+                (
+                    Vector2Int {
+                        x: index as i32 % width,
+                        y: index as i32 / width,
+                    },
+                    *cell,
+                )
+            });
+    }
+
+    /// Convert a position to an index into the cell list
+    fn index(&self, position: Vector2Int) -> usize {
+        return (position.y * self.size.x + position.x) as usize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::style::Style;
+    use crate::types::color32::Color32;
+
+    const SIZE: Vector2Int = Vector2Int { x: 4, y: 3 };
+
+    // Get changed positions as tuples with their glyphs
+    fn changes(buffer: &FrameBuffer, other: &FrameBuffer) -> Vec<((i32, i32), char)> {
+        return buffer
+            .diff(other)
+            .map(|(position, cell)| ((position.x, position.y), cell.glyph))
+            .collect();
+    }
+
+    #[test]
+    fn equal_buffers_have_no_diff() {
+        let buffer = FrameBuffer::new(SIZE, Cell::blank());
+        assert!(changes(&buffer, &buffer.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff_gives_changed_cells_in_row_order() {
+        let previous = FrameBuffer::new(SIZE, Cell::blank());
+        let mut buffer = previous.clone();
+        buffer.set(Vector2Int { x: 3, y: 2 }, Cell::new('c'));
+        buffer.set(Vector2Int { x: 0, y: 1 }, Cell::new('b'));
+        buffer.set(Vector2Int { x: 1, y: 0 }, Cell::new('a'));
+        // Setting a cell back to what it was is no change
+        buffer.set(Vector2Int { x: 2, y: 2 }, Cell::new('x'));
+        buffer.set(Vector2Int { x: 2, y: 2 }, Cell::blank());
+
+        assert_eq!(
+            changes(&buffer, &previous),
+            vec![((1, 0), 'a'), ((0, 1), 'b'), ((3, 2), 'c')]
+        );
+        assert_eq!(
+            changes(&previous, &buffer),
+            vec![((1, 0), ' '), ((0, 1), ' '), ((3, 2), ' ')]
+        );
+    }
+
+    #[test]
+    fn diff_compares_colors_and_styles() {
+        let previous = FrameBuffer::new(SIZE, Cell::new('a'));
+        let mut buffer = previous.clone();
+        let position = Vector2Int { x: 1, y: 1 };
+        buffer.set(position, Cell::new('a').with_foreground(Color32::red()));
+        assert_eq!(changes(&buffer, &previous), vec![((1, 1), 'a')]);
+
+        buffer.set(position, Cell::new('a').with_style(Style::BOLD));
+        let (_, cell) = buffer.diff(&previous).next().unwrap();
+        assert_eq!(cell, Cell::new('a').with_style(Style::BOLD));
+    }
+
+    #[test]
+    fn ignores_positions_outside() {
+        let mut buffer = FrameBuffer::new(SIZE, Cell::blank());
+        buffer.set(Vector2Int { x: 4, y: 0 }, Cell::new('x'));
+        buffer.set(Vector2Int { x: -1, y: 1 }, Cell::new('x'));
+        assert!(buffer.get(Vector2Int { x: 0, y: 3 }).is_none());
+        assert!(changes(&buffer, &FrameBuffer::new(SIZE, Cell::blank())).is_empty());
+
+        buffer.fill(Cell::new('f'));
+        buffer.resize(Vector2Int { x: 2, y: 2 }, Cell::blank());
+        assert_eq!(buffer.get(Vector2Int { x: 1, y: 1 }), Some(Cell::blank()));
+        assert!(buffer.get(Vector2Int { x: 2, y: 1 }).is_none());
+    }
+}
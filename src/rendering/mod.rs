@@ -0,0 +1,7 @@
+pub mod camera;
+pub mod cell;
+pub mod color_depth;
+pub mod frame_buffer;
+pub mod render_queue;
+pub mod style;
+pub mod viewport;
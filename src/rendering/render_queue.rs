@@ -0,0 +1,97 @@
+use crate::{app::App, entities::game_object::GameObject, types::vector2int::Vector2Int};
+use std::collections::HashSet;
+
+/// Something that can be queued for rendering and drawn onto an App's frame
+pub trait Renderable {
+    /// Get the GameObject the renderable is placed and ordered by
+    fn get_game_object(&self) -> GameObject;
+
+    /// Get size in cells
+    fn get_size(&self) -> Vector2Int;
+
+    /// Draw onto App's frame immediately
+    fn draw(&self, app: &mut App);
+}
+
+/// Renderables submitted during a frame, drawn in layer order once the frame is rendered
+pub struct RenderQueue {
+    items: Vec<Box<dyn Renderable>>,
+    y_sorted_layers: HashSet<i32>,
+    screen_space_layers: HashSet<i32>,
+}
+
+impl RenderQueue {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self {
+            items: Vec::new(),
+            y_sorted_layers: HashSet::new(),
+            screen_space_layers: HashSet::new(),
+        };
+    }
+
+    /// Queue a renderable to be drawn
+    pub fn submit(&mut self, item: Box<dyn Renderable>) {
+        self.items.push(item);
+    }
+
+    /// Get number of queued renderables
+    pub fn len(&self) -> usize {
+        return self.items.len();
+    }
+
+    /// Check if nothing is queued
+    pub fn is_empty(&self) -> bool {
+        return self.items.is_empty();
+    }
+
+    /// Set whether objects within a layer are drawn from top to bottom of the screen,
+    /// so that objects lower on screen overlap objects behind them
+    pub fn set_layer_y_sorted(&mut self, layer: i32, value: bool) {
+        if value {
+            self.y_sorted_layers.insert(layer);
+        } else {
+            self.y_sorted_layers.remove(&layer);
+        }
+    }
+
+    /// Check if objects within a layer are sorted by their bottom edge
+    pub fn is_layer_y_sorted(&self, layer: i32) -> bool {
+        return self.y_sorted_layers.contains(&layer);
+    }
+
+    /// Set whether objects within a layer are placed in screen coordinates, ignoring
+    /// viewports and cameras (for HUDs and menus)
+    pub fn set_layer_screen_space(&mut self, layer: i32, value: bool) {
+        if value {
+            self.screen_space_layers.insert(layer);
+        } else {
+            self.screen_space_layers.remove(&layer);
+        }
+    }
+
+    /// Check if objects within a layer are placed in screen coordinates
+    pub fn is_layer_screen_space(&self, layer: i32) -> bool {
+        return self.screen_space_layers.contains(&layer);
+    }
+
+    /// Take queued renderables sorted in draw order, leaving the queue empty
+    ///
+    /// Renderables are sorted by layer, then by bottom edge in y-sorted layers, then by
+    /// z-index. Ties keep submission order
+    pub fn take_sorted(&mut self) -> Vec<Box<dyn Renderable>> {
+        let mut items = std::mem::take(&mut self.items);
+        let y_sorted_layers = &self.y_sorted_layers;
+        items.sort_by_key(|item| {
+            let game_object = item.get_game_object();
+            let layer = game_object.get_layer();
+            let y = if y_sorted_layers.contains(&layer) {
+                game_object.get_position().y + item.get_size().y
+            } else {
+                0
+            };
+            (layer, y, game_object.get_z_index())
+        });
+        return items;
+    }
+}
@@ -0,0 +1,81 @@
+use std::ops;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// A set of text attributes, combined with `|`
+pub struct Style {
+    bits: u8,
+}
+
+// Logical operator overloading for type Style
+impl ops::BitOr for Style {
+    type Output = Style;
+    fn bitor(self, rhs: Style) -> Style {
+        return Style {
+            bits: self.bits | rhs.bits,
+        };
+    }
+}
+
+impl ops::BitOrAssign for Style {
+    fn bitor_assign(&mut self, rhs: Style) {
+        self.bits |= rhs.bits;
+    }
+}
+
+impl Style {
+    pub const NONE: Style = Style { bits: 0 };
+    pub const BOLD: Style = Style { bits: 1 };
+    pub const DIM: Style = Style { bits: 1 << 1 };
+    pub const ITALIC: Style = Style { bits: 1 << 2 };
+    pub const UNDERLINE: Style = Style { bits: 1 << 3 };
+    pub const BLINK: Style = Style { bits: 1 << 4 };
+    pub const REVERSE: Style = Style { bits: 1 << 5 };
+
+    /// Every attribute in display order
+    pub const ALL: [Style; 6] = [
+        Style::BOLD,
+        Style::DIM,
+        Style::ITALIC,
+        Style::UNDERLINE,
+        Style::BLINK,
+        Style::REVERSE,
+    ];
+
+    /// Default constructor, no attributes
+    pub fn new() -> Self {
+        return Style::NONE;
+    }
+
+    /// Check if no attributes are set
+    pub fn is_empty(&self) -> bool {
+        return self.bits == 0;
+    }
+
+    /// Check if all attributes of another style are set
+    pub fn contains(&self, other: Style) -> bool {
+        return self.bits & other.bits == other.bits;
+    }
+
+    /// Set attributes of another style
+    pub fn insert(&mut self, other: Style) {
+        self.bits |= other.bits;
+    }
+
+    /// Unset attributes of another style
+    pub fn remove(&mut self, other: Style) {
+        self.bits &= !other.bits;
+    }
+
+    /// Select Graphic Rendition code of a single attribute, used by escape code backends
+    pub fn to_sgr_code(self) -> Option<u8> {
+        return match self {
+            Style::BOLD => Some(1),
+            Style::DIM => Some(2),
+            Style::ITALIC => Some(3),
+            Style::UNDERLINE => Some(4),
+            Style::BLINK => Some(5),
+            Style::REVERSE => Some(7),
+            _ => None,
+        };
+    }
+}
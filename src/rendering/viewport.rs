@@ -0,0 +1,44 @@
+use super::camera::Camera;
+use crate::types::{rect_int::RectInt, vector2int::Vector2Int};
+
+#[derive(Copy, Clone)]
+/// A screen area showing the game world through a camera
+pub struct Viewport {
+    pub rect: RectInt,
+    pub camera: Camera,
+}
+
+impl Viewport {
+    /// Default constructor, showing the world from its origin
+    pub fn new(rect: RectInt) -> Self {
+        return Self {
+            rect,
+            camera: Camera::new(),
+        };
+    }
+
+    /// Get world area currently shown
+    pub fn get_world_view(&self) -> RectInt {
+        return self.camera.get_view(self.rect.size);
+    }
+
+    /// Convert a world position to a screen position
+    pub fn world_to_screen(&self, world_position: Vector2Int) -> Vector2Int {
+        return world_position - self.camera.get_position() + self.rect.position;
+    }
+
+    /// Convert a screen position to a world position
+    pub fn screen_to_world(&self, screen_position: Vector2Int) -> Vector2Int {
+        return screen_position - self.rect.position + self.camera.get_position();
+    }
+
+    /// Check if any part of a world area is shown
+    pub fn is_visible(&self, world_rect: RectInt) -> bool {
+        return self.get_world_view().intersects(world_rect);
+    }
+
+    /// Update camera for this viewport's size
+    pub fn update(&mut self) {
+        self.camera.update(self.rect.size);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::app::App;
+
+/// A state of the game (title screen, gameplay, pause menu...) living on the App's scene stack
+///
+/// Only the scene on top of the stack is updated, every hook has an empty default
+pub trait Scene {
+    /// Called when the scene is put on the stack
+    fn enter(&mut self, _app: &mut App) {}
+
+    /// Called when the scene is taken off the stack
+    fn exit(&mut self, _app: &mut App) {}
+
+    /// Called when another scene is pushed on top of this one
+    fn pause(&mut self, _app: &mut App) {}
+
+    /// Called when this scene is on top of the stack again
+    fn resume(&mut self, _app: &mut App) {}
+
+    /// Called every frame while the scene is on top of the stack
+    fn update(&mut self, _app: &mut App) {}
+
+    /// Called every frame while the scene is visible
+    fn render(&mut self, _app: &mut App) {}
+
+    /// Whether scenes underneath keep being rendered while this one is above them
+    fn is_overlay(&self) -> bool {
+        return false;
+    }
+}
+
+/// A change of the scene stack, applied once the current scene is done updating
+pub enum SceneCommand {
+    Push(Box<dyn Scene>),
+    Pop,
+    Replace(Box<dyn Scene>),
+    Clear,
+}
+
+/// Stack of scenes, the last one being on top
+pub struct SceneStack {
+    scenes: Vec<Box<dyn Scene>>,
+    commands: Vec<SceneCommand>,
+}
+
+impl SceneStack {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self {
+            scenes: Vec::new(),
+            commands: Vec::new(),
+        };
+    }
+
+    /// Get number of scenes on the stack
+    pub fn len(&self) -> usize {
+        return self.scenes.len();
+    }
+
+    /// Check if there is no scene on the stack
+    pub fn is_empty(&self) -> bool {
+        return self.scenes.is_empty();
+    }
+
+    /// Queue a change of the stack
+    pub fn queue(&mut self, command: SceneCommand) {
+        self.commands.push(command);
+    }
+
+    /// Check if there are changes waiting to be applied
+    pub fn has_commands(&self) -> bool {
+        return !self.commands.is_empty();
+    }
+
+    /// Take queued changes out of the stack
+    pub fn take_commands(&mut self) -> Vec<SceneCommand> {
+        return std::mem::take(&mut self.commands);
+    }
+
+    /// Put a scene on top of the stack
+    pub fn push(&mut self, scene: Box<dyn Scene>) {
+        self.scenes.push(scene);
+    }
+
+    /// Take the scene on top of the stack
+    pub fn pop(&mut self) -> Option<Box<dyn Scene>> {
+        return self.scenes.pop();
+    }
+
+    /// Index of the lowest scene to render, scenes under an overlay stay visible
+    pub fn get_first_visible(&self) -> usize {
+        let mut index = self.scenes.len();
+        while index > 0 {
+            index -= 1;
+            if !self.scenes[index].is_overlay() {
+                return index;
+            }
+        }
+        return 0;
+    }
+
+    /// Take scenes from an index to the top of the stack
+    pub fn split_off(&mut self, index: usize) -> Vec<Box<dyn Scene>> {
+        return self.scenes.split_off(index.min(self.scenes.len()));
+    }
+
+    /// Put scenes back on top of the stack, the last one being on top
+    pub fn append(&mut self, scenes: &mut Vec<Box<dyn Scene>>) {
+        self.scenes.append(scenes);
+    }
+}
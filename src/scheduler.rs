@@ -0,0 +1,230 @@
+use crate::app::App;
+
+/// A function run by the scheduler
+pub type Callback = Box<dyn FnMut(&mut App)>;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// Handle to a scheduled timer or sequence, used to cancel it
+pub struct TimerHandle {
+    id: u64,
+}
+
+/// A step of a sequence
+pub enum Step {
+    /// Wait a number of seconds before the next step
+    Wait(f32),
+    /// Run a callback then go on with the next step right away
+    Call(Callback),
+}
+
+/// Chained steps run one after the other, e.g. wait 1s, flash, wait 0.5s, respawn
+pub struct Sequence {
+    steps: Vec<Step>,
+}
+
+impl Sequence {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self { steps: Vec::new() };
+    }
+
+    /// Add a wait of a number of seconds
+    pub fn wait(mut self, seconds: f32) -> Sequence {
+        self.steps.push(Step::Wait(seconds));
+        return self;
+    }
+
+    /// Add a callback
+    pub fn then(mut self, callback: impl FnMut(&mut App) + 'static) -> Sequence {
+        self.steps.push(Step::Call(Box::new(callback)));
+        return self;
+    }
+
+    /// Get number of steps
+    pub fn len(&self) -> usize {
+        return self.steps.len();
+    }
+
+    /// Check if the sequence has no step
+    pub fn is_empty(&self) -> bool {
+        return self.steps.is_empty();
+    }
+}
+
+/// A pending sequence and how far it went
+struct Task {
+    handle: TimerHandle,
+    steps: Vec<Step>,
+    current_step: usize,
+    // Time left on the current wait
+    remaining: f32,
+    is_repeating: bool,
+}
+
+/// Runs callbacks after a delay, at an interval or as sequences, driven by App time
+pub struct Scheduler {
+    tasks: Vec<Task>,
+    next_id: u64,
+}
+
+impl Scheduler {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self {
+            tasks: Vec::new(),
+            next_id: 0,
+        };
+    }
+
+    /// Run a callback once after a number of seconds
+    pub fn after(&mut self, seconds: f32, callback: impl FnMut(&mut App) + 'static) -> TimerHandle {
+        return self.run(Sequence::new().wait(seconds).then(callback));
+    }
+
+    /// Run a callback every number of seconds until the timer is cancelled
+    pub fn every(&mut self, seconds: f32, callback: impl FnMut(&mut App) + 'static) -> TimerHandle {
+        return self.repeat(Sequence::new().wait(seconds).then(callback));
+    }
+
+    /// Start running a sequence once
+    pub fn run(&mut self, sequence: Sequence) -> TimerHandle {
+        return self.add_task(sequence, false);
+    }
+
+    /// Start running a sequence over and over until it is cancelled
+    ///
+    /// A sequence restarts at most once per update, so it should wait somewhere, and one
+    /// without steps never runs
+    pub fn repeat(&mut self, sequence: Sequence) -> TimerHandle {
+        return self.add_task(sequence, true);
+    }
+
+    /// Stop a timer or sequence, callbacks can cancel their own timer
+    pub fn cancel(&mut self, handle: TimerHandle) {
+        self.tasks.retain(|task| task.handle != handle);
+    }
+
+    /// Stop every timer and sequence
+    pub fn cancel_all(&mut self) {
+        self.tasks.clear();
+    }
+
+    /// Check if a timer or sequence has not finished yet
+    pub fn is_pending(&self, handle: TimerHandle) -> bool {
+        return self.tasks.iter().any(|task| task.handle == handle);
+    }
+
+    /// Get number of pending timers and sequences
+    pub fn len(&self) -> usize {
+        return self.tasks.len();
+    }
+
+    /// Check if nothing is scheduled
+    pub fn is_empty(&self) -> bool {
+        return self.tasks.is_empty();
+    }
+
+    /// Advance every task of the App's scheduler by a number of seconds, running
+    /// callbacks that are due
+    ///
+    /// Tasks stay in the scheduler while they run, so callbacks see and change it as usual
+    pub(crate) fn update(app: &mut App, delta: f32) {
+        let handles: Vec<TimerHandle> =
+            app.scheduler.tasks.iter().map(|task| task.handle).collect();
+        for handle in handles {
+            let mut delta = delta;
+            let mut has_restarted = false;
+            // Stop as soon as a callback cancels the task
+            while let Some(task) = app.scheduler.get_task_mut(handle) {
+                task.remaining -= delta;
+                delta = 0.0;
+                if task.remaining > 0.0 {
+                    break;
+                }
+                if task.current_step >= task.steps.len() {
+                    // Sequences without steps have nothing to restart
+                    if !task.is_repeating || has_restarted || task.steps.is_empty() {
+                        break;
+                    }
+                    task.current_step = 0;
+                    has_restarted = true;
+                }
+                let step = task.current_step;
+                task.current_step += 1;
+
+                // Take the callback out while it runs, putting it back if the task is still there
+                let mut callback = match &mut task.steps[step] {
+                    Step::Wait(seconds) => {
+                        task.remaining += *seconds;
+                        continue;
+                    }
+                    Step::Call(callback) => std::mem::replace(callback, Box::new(|_| {})),
+                };
+                callback(app);
+                if let Some(task) = app.scheduler.get_task_mut(handle) {
+                    task.steps[step] = Step::Call(callback);
+                }
+            }
+            // Drop the task right away once it is done, a trailing wait included, for
+            // later callbacks to see
+            app.scheduler.tasks.retain(|task| {
+                task.handle != handle
+                    || task.is_repeating
+                    || task.current_step < task.steps.len()
+                    || task.remaining > 0.0
+            });
+        }
+    }
+
+    fn get_task_mut(&mut self, handle: TimerHandle) -> Option<&mut Task> {
+        return self.tasks.iter_mut().find(|task| task.handle == handle);
+    }
+
+    fn add_task(&mut self, sequence: Sequence, is_repeating: bool) -> TimerHandle {
+        let handle = TimerHandle { id: self.next_id };
+        self.next_id += 1;
+        self.tasks.push(Task {
+            handle,
+            steps: sequence.steps,
+            current_step: 0,
+            remaining: 0.0,
+            is_repeating,
+        });
+        return handle;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::headless_backend::HeadlessBackend;
+    use crate::types::vector2int::Vector2Int;
+
+    fn new_app() -> App {
+        return App::with_backend(Box::new(HeadlessBackend::new(Vector2Int { x: 10, y: 5 })));
+    }
+
+    #[test]
+    fn empty_sequences_do_nothing() {
+        let mut app = new_app();
+        let once = app.scheduler.run(Sequence::new());
+        let repeated = app.scheduler.repeat(Sequence::new());
+        Scheduler::update(&mut app, 0.1);
+        Scheduler::update(&mut app, 0.1);
+        assert!(!app.scheduler.is_pending(once));
+        assert!(app.scheduler.is_pending(repeated));
+    }
+
+    #[test]
+    fn trailing_wait_keeps_sequence_pending() {
+        let mut app = new_app();
+        let handle = app.scheduler.run(Sequence::new().then(|_| {}).wait(1.0));
+        for _ in 0..9 {
+            Scheduler::update(&mut app, 0.1);
+            assert!(app.scheduler.is_pending(handle));
+        }
+        Scheduler::update(&mut app, 0.2);
+        assert!(!app.scheduler.is_pending(handle));
+        assert!(app.scheduler.is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+// Fixed steps run in a single frame at most, extra time is dropped so a slow
+// frame does not make the next ones even slower
+const MAX_FIXED_STEPS: u32 = 5;
+
+// How often the measured frame rate is refreshed, in seconds
+const FPS_INTERVAL: f32 = 0.5;
+
+/// Frame timing of an App: delta time, elapsed time, frame rate and fixed timestep
+pub struct Time {
+    start: Instant,
+    frame_start: Instant,
+    delta: f32,
+    elapsed: f32,
+    frame_count: u64,
+
+    // Measured frame rate, counted over FPS_INTERVAL
+    fps: f32,
+    fps_timer: f32,
+    fps_frames: u32,
+
+    // Fixed timestep, the accumulator holds time not yet consumed by fixed steps
+    fixed_delta: f32,
+    accumulator: f32,
+}
+
+impl Time {
+    /// Default constructor, with a fixed timestep
+    pub fn new(fixed_delta: f32) -> Self {
+        let now = Instant::now();
+        return Self {
+            start: now,
+            frame_start: now,
+            delta: 0.0,
+            elapsed: 0.0,
+            frame_count: 0,
+
+            fps: 0.0,
+            fps_timer: 0.0,
+            fps_frames: 0,
+
+            fixed_delta,
+            accumulator: 0.0,
+        };
+    }
+
+    /// Start a new frame, measuring time since the previous one
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        if self.frame_count > 0 {
+            self.delta = now.duration_since(self.frame_start).as_secs_f32();
+        }
+        self.frame_start = now;
+        self.elapsed = now.duration_since(self.start).as_secs_f32();
+        self.frame_count += 1;
+
+        self.fps_timer += self.delta;
+        self.fps_frames += 1;
+        if self.fps_timer >= FPS_INTERVAL {
+            self.fps = self.fps_frames as f32 / self.fps_timer;
+            self.fps_timer = 0.0;
+            self.fps_frames = 0;
+        }
+
+        let max_accumulated = self.fixed_delta * MAX_FIXED_STEPS as f32;
+        self.accumulator = (self.accumulator + self.delta).min(max_accumulated);
+    }
+
+    /// Consume a fixed step from the accumulator, returning false when there is not enough time left
+    pub fn consume_fixed_step(&mut self) -> bool {
+        if self.fixed_delta <= 0.0 || self.accumulator < self.fixed_delta {
+            return false;
+        }
+        self.accumulator -= self.fixed_delta;
+        return true;
+    }
+
+    /// Get seconds between the start of the previous frame and the current one
+    pub fn get_delta(&self) -> f32 {
+        return self.delta;
+    }
+
+    /// Get seconds since the App started
+    pub fn get_elapsed(&self) -> f32 {
+        return self.elapsed;
+    }
+
+    /// Get number of frames started, including the current one
+    pub fn get_frame_count(&self) -> u64 {
+        return self.frame_count;
+    }
+
+    /// Get measured frames per second
+    pub fn get_fps(&self) -> f32 {
+        return self.fps;
+    }
+
+    /// Get time spent in the current frame so far
+    pub fn get_frame_time(&self) -> Duration {
+        return self.frame_start.elapsed();
+    }
+
+    /// Get seconds between two fixed updates
+    pub fn get_fixed_delta(&self) -> f32 {
+        return self.fixed_delta;
+    }
+
+    /// Set seconds between two fixed updates
+    pub fn set_fixed_delta(&mut self, fixed_delta: f32) {
+        self.fixed_delta = fixed_delta;
+        self.accumulator = 0.0;
+    }
+
+    /// Get how far the current frame is between the last fixed update and the next one,
+    /// from 0 to 1, to interpolate positions when rendering
+    pub fn get_alpha(&self) -> f32 {
+        if self.fixed_delta <= 0.0 {
+            return 0.0;
+        }
+        return (self.accumulator / self.fixed_delta).clamp(0.0, 1.0);
+    }
+}
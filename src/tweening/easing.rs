@@ -0,0 +1,141 @@
+use std::f32::consts::PI;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Standard easing curves, mapping progress from 0 to 1 to an eased progress
+///
+/// In curves start slow, Out curves end slow, InOut curves do both
+pub enum Easing {
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+// Overshoot of Back curves
+const BACK_C1: f32 = 1.70158;
+const BACK_C2: f32 = BACK_C1 * 1.525;
+const BACK_C3: f32 = BACK_C1 + 1.0;
+
+// Period of Elastic curves
+const ELASTIC_C4: f32 = (2.0 * PI) / 3.0;
+const ELASTIC_C5: f32 = (2.0 * PI) / 4.5;
+
+impl Easing {
+    /// Get eased progress, Back and Elastic curves go slightly out of 0 to 1
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        return match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::QuadInOut => in_out(t, |t| t * t),
+            Easing::CubicIn => t.powi(3),
+            Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut => in_out(t, |t| t.powi(3)),
+            Easing::QuartIn => t.powi(4),
+            Easing::QuartOut => 1.0 - (1.0 - t).powi(4),
+            Easing::QuartInOut => in_out(t, |t| t.powi(4)),
+            Easing::QuintIn => t.powi(5),
+            Easing::QuintOut => 1.0 - (1.0 - t).powi(5),
+            Easing::QuintInOut => in_out(t, |t| t.powi(5)),
+            Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            Easing::SineOut => (t * PI / 2.0).sin(),
+            Easing::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::ExpoIn => expo_in(t),
+            Easing::ExpoOut => 1.0 - expo_in(1.0 - t),
+            Easing::ExpoInOut => in_out(t, expo_in),
+            Easing::CircIn => circ_in(t),
+            Easing::CircOut => 1.0 - circ_in(1.0 - t),
+            Easing::CircInOut => in_out(t, circ_in),
+            Easing::BackIn => BACK_C3 * t.powi(3) - BACK_C1 * t * t,
+            Easing::BackOut => 1.0 + BACK_C3 * (t - 1.0).powi(3) + BACK_C1 * (t - 1.0).powi(2),
+            Easing::BackInOut => in_out(t, |t| (BACK_C2 + 1.0) * t.powi(3) - BACK_C2 * t * t),
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => 1.0 - elastic_in(1.0 - t),
+            Easing::ElasticInOut => elastic_in_out(t),
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+        };
+    }
+}
+
+/// Build an InOut curve from an In curve, running it on each half
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        return ease_in(t * 2.0) / 2.0;
+    }
+    return 1.0 - ease_in((1.0 - t) * 2.0) / 2.0;
+}
+
+fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        return 0.0;
+    }
+    return 2f32.powf(10.0 * t - 10.0);
+}
+
+fn circ_in(t: f32) -> f32 {
+    return 1.0 - (1.0 - t * t).sqrt();
+}
+
+fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    return -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * ELASTIC_C4).sin();
+}
+
+fn elastic_in_out(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    let wave = ((20.0 * t - 11.125) * ELASTIC_C5).sin();
+    if t < 0.5 {
+        return -(2f32.powf(20.0 * t - 10.0) * wave) / 2.0;
+    }
+    return 2f32.powf(-20.0 * t + 10.0) * wave / 2.0 + 1.0;
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+    if t < 1.0 / D1 {
+        return N1 * t * t;
+    } else if t < 2.0 / D1 {
+        let t = t - 1.5 / D1;
+        return N1 * t * t + 0.75;
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        return N1 * t * t + 0.9375;
+    }
+    let t = t - 2.625 / D1;
+    return N1 * t * t + 0.984375;
+}
@@ -0,0 +1,43 @@
+use crate::types::{color::Color, vector2::Vector2, vector2int::Vector2Int};
+
+/// Values a tween can go through
+pub trait Lerp: Copy {
+    /// Get the value at a progress between this value (0) and another one (1)
+    fn lerp(self, to: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(self, to: f32, t: f32) -> f32 {
+        return self + (to - self) * t;
+    }
+}
+
+impl Lerp for Vector2 {
+    fn lerp(self, to: Vector2, t: f32) -> Vector2 {
+        return Vector2 {
+            x: self.x.lerp(to.x, t),
+            y: self.y.lerp(to.y, t),
+        };
+    }
+}
+
+impl Lerp for Vector2Int {
+    /// Positions are rounded to the nearest cell
+    fn lerp(self, to: Vector2Int, t: f32) -> Vector2Int {
+        return Vector2Int {
+            x: (self.x as f32).lerp(to.x as f32, t).round() as i32,
+            y: (self.y as f32).lerp(to.y as f32, t).round() as i32,
+        };
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(self, to: Color, t: f32) -> Color {
+        return Color {
+            r: self.r.lerp(to.r, t),
+            g: self.g.lerp(to.g, t),
+            b: self.b.lerp(to.b, t),
+            a: self.a.lerp(to.a, t),
+        };
+    }
+}
@@ -0,0 +1,4 @@
+pub mod easing;
+pub mod lerp;
+pub mod tween;
+pub mod tweens;
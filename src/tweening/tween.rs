@@ -0,0 +1,168 @@
+use super::{easing::Easing, lerp::Lerp};
+use crate::app::App;
+use crate::ecs::entity::Entity;
+use crate::entities::game_object::GameObject;
+use crate::scheduler::Callback;
+use crate::types::vector2int::Vector2Int;
+
+/// A function getting the value of a tween every time it is ticked
+pub type UpdateCallback<T> = Box<dyn FnMut(&mut App, T)>;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// What a tween does once it reaches its end value
+pub enum TweenMode {
+    /// Stop at the end value
+    Once,
+    /// Start again from the start value
+    Loop,
+    /// Go back and forth between start and end values
+    Yoyo,
+}
+
+/// An animation of a value from a start value to an end value over a duration
+///
+/// Added to `app.tweens` it is ticked by the App and hands its value to an update callback,
+/// e.g. to move a GameObject, or it can be stepped manually
+pub struct Tween<T: Lerp> {
+    from: T,
+    to: T,
+    duration: f32,
+    elapsed: f32,
+    easing: Easing,
+    mode: TweenMode,
+    // Times the tween plays before completing, each way of a yoyo counting once
+    repeat_count: Option<u32>,
+    play_count: u32,
+    is_reversed: bool,
+    is_finished: bool,
+    on_update: Option<UpdateCallback<T>>,
+    on_complete: Option<Callback>,
+}
+
+impl<T: Lerp> Tween<T> {
+    /// Default constructor, a linear tween played once
+    pub fn new(from: T, to: T, duration: f32) -> Self {
+        return Self {
+            from,
+            to,
+            duration,
+            elapsed: 0.0,
+            easing: Easing::Linear,
+            mode: TweenMode::Once,
+            repeat_count: None,
+            play_count: 0,
+            is_reversed: false,
+            is_finished: false,
+            on_update: None,
+            on_complete: None,
+        };
+    }
+
+    /// Set easing curve
+    pub fn set_easing(mut self, easing: Easing) -> Tween<T> {
+        self.easing = easing;
+        return self;
+    }
+
+    /// Set what the tween does once it reaches its end value
+    pub fn set_mode(mut self, mode: TweenMode) -> Tween<T> {
+        self.mode = mode;
+        return self;
+    }
+
+    /// Set how many times a looping or yoyo tween plays before completing, forever by default
+    pub fn set_repeat_count(mut self, repeat_count: u32) -> Tween<T> {
+        self.repeat_count = Some(repeat_count);
+        return self;
+    }
+
+    /// Set a callback getting the value every time the tween is ticked by the App
+    pub fn on_update(mut self, callback: impl FnMut(&mut App, T) + 'static) -> Tween<T> {
+        self.on_update = Some(Box::new(callback));
+        return self;
+    }
+
+    /// Set a callback run once the tween completes
+    pub fn on_complete(mut self, callback: impl FnMut(&mut App) + 'static) -> Tween<T> {
+        self.on_complete = Some(Box::new(callback));
+        return self;
+    }
+
+    /// Get current value
+    pub fn get_value(&self) -> T {
+        let mut progress = if self.duration > 0.0 {
+            self.elapsed / self.duration
+        } else {
+            1.0
+        };
+        if self.is_reversed {
+            progress = 1.0 - progress;
+        }
+        return self.from.lerp(self.to, self.easing.apply(progress));
+    }
+
+    /// Check if the tween has completed
+    pub fn is_finished(&self) -> bool {
+        return self.is_finished;
+    }
+
+    /// Start the tween again from its start value
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+        self.play_count = 0;
+        self.is_reversed = false;
+        self.is_finished = false;
+    }
+
+    /// Advance the tween by a number of seconds and get its new value
+    pub fn step(&mut self, delta: f32) -> T {
+        if self.is_finished {
+            return self.get_value();
+        }
+        self.elapsed += delta;
+        while self.elapsed >= self.duration {
+            self.play_count += 1;
+            let is_last_play = match self.mode {
+                TweenMode::Once => true,
+                _ => self
+                    .repeat_count
+                    .is_some_and(|count| self.play_count >= count),
+            };
+            if is_last_play || self.duration <= 0.0 {
+                self.elapsed = self.duration;
+                self.is_finished = true;
+                break;
+            }
+            self.elapsed -= self.duration;
+            if self.mode == TweenMode::Yoyo {
+                self.is_reversed = !self.is_reversed;
+            }
+        }
+        return self.get_value();
+    }
+
+    /// Advance the tween and run its callbacks, returning whether it has completed
+    pub fn update(&mut self, delta: f32, app: &mut App) -> bool {
+        let value = self.step(delta);
+        if let Some(on_update) = self.on_update.as_mut() {
+            on_update(app, value);
+        }
+        if self.is_finished {
+            if let Some(mut on_complete) = self.on_complete.take() {
+                on_complete(app);
+            }
+        }
+        return self.is_finished;
+    }
+}
+
+impl Tween<Vector2Int> {
+    /// Tween moving the GameObject component of an entity
+    pub fn move_entity(entity: Entity, from: Vector2Int, to: Vector2Int, duration: f32) -> Self {
+        return Tween::new(from, to, duration).on_update(move |app: &mut App, position| {
+            if let Some(game_object) = app.world.get_component_mut::<GameObject>(entity) {
+                game_object.move_to(position);
+            }
+        });
+    }
+}
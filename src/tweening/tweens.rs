@@ -0,0 +1,106 @@
+use super::{lerp::Lerp, tween::Tween};
+use crate::app::App;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// Handle to a running tween, used to cancel it
+pub struct TweenHandle {
+    id: u64,
+}
+
+/// A tween of any value type
+trait AnyTween {
+    fn update(&mut self, delta: f32, app: &mut App) -> bool;
+}
+
+impl<T: Lerp> AnyTween for Tween<T> {
+    fn update(&mut self, delta: f32, app: &mut App) -> bool {
+        return Tween::update(self, delta, app);
+    }
+}
+
+/// Every tween ticked by the App, removed once they complete
+pub struct Tweens {
+    // Tweens are taken out of their slot while they update
+    tweens: Vec<(TweenHandle, Option<Box<dyn AnyTween>>)>,
+    next_id: u64,
+}
+
+impl Tweens {
+    /// Default constructor
+    pub fn new() -> Self {
+        return Self {
+            tweens: Vec::new(),
+            next_id: 0,
+        };
+    }
+
+    /// Start ticking a tween
+    pub fn add<T: Lerp + 'static>(&mut self, tween: Tween<T>) -> TweenHandle {
+        let handle = TweenHandle { id: self.next_id };
+        self.next_id += 1;
+        self.tweens.push((handle, Some(Box::new(tween))));
+        return handle;
+    }
+
+    /// Stop a tween without completing it
+    pub fn cancel(&mut self, handle: TweenHandle) {
+        self.tweens
+            .retain(|(tween_handle, _)| *tween_handle != handle);
+    }
+
+    /// Stop every tween
+    pub fn cancel_all(&mut self) {
+        self.tweens.clear();
+    }
+
+    /// Check if a tween has not completed yet
+    pub fn is_running(&self, handle: TweenHandle) -> bool {
+        return self
+            .tweens
+            .iter()
+            .any(|(tween_handle, _)| *tween_handle == handle);
+    }
+
+    /// Get number of running tweens
+    pub fn len(&self) -> usize {
+        return self.tweens.len();
+    }
+
+    /// Check if no tween is running
+    pub fn is_empty(&self) -> bool {
+        return self.tweens.is_empty();
+    }
+
+    /// Advance every tween of the App by a number of seconds, removing completed ones
+    ///
+    /// Tweens stay listed while they update, so callbacks see and change the list as usual
+    pub(crate) fn update(app: &mut App, delta: f32) {
+        let handles: Vec<TweenHandle> = app
+            .tweens
+            .tweens
+            .iter()
+            .map(|(handle, _)| *handle)
+            .collect();
+        for handle in handles {
+            let mut tween = match app.tweens.get_slot(handle).and_then(Option::take) {
+                Some(tween) => tween,
+                None => continue,
+            };
+            let is_complete = tween.update(delta, app);
+            // Put the tween back unless it completed or was cancelled meanwhile
+            if is_complete {
+                app.tweens.cancel(handle);
+            } else if let Some(slot) = app.tweens.get_slot(handle) {
+                *slot = Some(tween);
+            }
+        }
+    }
+
+    fn get_slot(&mut self, handle: TweenHandle) -> Option<&mut Option<Box<dyn AnyTween>>> {
+        return self
+            .tweens
+            .iter_mut()
+            .find(|(tween_handle, _)| *tween_handle == handle)
+            .map(|(_, slot)| slot);
+    }
+}
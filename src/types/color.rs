@@ -290,34 +290,10 @@ impl Color {
     /// Color normalized so that its RGBA values are between 0 and 1
     pub fn normalized(&mut self) -> Color {
         return Color {
-            r: if self.r > 1.0 {
-                1.0
-            } else if self.r < 0.0 {
-                0.0
-            } else {
-                self.r
-            },
-            g: if self.g > 1.0 {
-                1.0
-            } else if self.g < 0.0 {
-                0.0
-            } else {
-                self.g
-            },
-            b: if self.b > 1.0 {
-                1.0
-            } else if self.b < 0.0 {
-                0.0
-            } else {
-                self.b
-            },
-            a: if self.a > 1.0 {
-                1.0
-            } else if self.a < 0.0 {
-                0.0
-            } else {
-                self.a
-            },
+            r: self.r.clamp(0.0, 1.0),
+            g: self.g.clamp(0.0, 1.0),
+            b: self.b.clamp(0.0, 1.0),
+            a: self.a.clamp(0.0, 1.0),
         };
     }
 }
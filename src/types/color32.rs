@@ -2,6 +2,7 @@ use crate::types::color::Color;
 use std::fmt;
 use std::ops;
 
+#[derive(Debug)]
 /// A type that holds the red, green, blue and alpha channel of a color (in 32 bit format)
 pub struct Color32 {
     pub r: u32,
@@ -170,6 +171,19 @@ impl ops::DivAssign for Color32 {
     }
 }
 
+// Conversion from Color type to Color32 type
+impl From<Color> for Color32 {
+    fn from(color: Color) -> Color32 {
+        let to_channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u32;
+        return Color32 {
+            r: to_channel(color.r),
+            g: to_channel(color.g),
+            b: to_channel(color.b),
+            a: to_channel(color.a),
+        };
+    }
+}
+
 // Logical operator overloading for type Color32
 impl PartialEq for Color32 {
     fn eq(&self, other: &Color32) -> bool {
@@ -302,4 +316,85 @@ impl Color32 {
             a: (self.a / 255) as f32,
         };
     }
+
+    /// Check if color is fully transparent, meaning the terminal's default color is used
+    pub fn is_transparent(&self) -> bool {
+        return self.a == 0;
+    }
+
+    /// Closest color in the 256 color terminal palette
+    pub fn to_ansi256(&self) -> u8 {
+        let color = { *self }.normalized();
+
+        // Closest color in the 6x6x6 color cube
+        let cube_levels = [0, 95, 135, 175, 215, 255];
+        let to_cube_index = |value: u32| -> usize {
+            if value < 48 {
+                0
+            } else if value < 115 {
+                1
+            } else {
+                ((value - 35) / 40) as usize
+            }
+        };
+        let (r, g, b) = (
+            to_cube_index(color.r),
+            to_cube_index(color.g),
+            to_cube_index(color.b),
+        );
+        let cube = Color32 {
+            r: cube_levels[r],
+            g: cube_levels[g],
+            b: cube_levels[b],
+            a: 255,
+        };
+
+        // Closest color in the 24 step gray ramp
+        let average = (color.r + color.g + color.b) / 3;
+        let gray_index = if average > 238 {
+            23
+        } else {
+            (average.max(8) - 8) / 10
+        };
+        let gray_level = 8 + gray_index * 10;
+        let gray = Color32 {
+            r: gray_level,
+            g: gray_level,
+            b: gray_level,
+            a: 255,
+        };
+
+        return if color.distance_squared(&gray) < color.distance_squared(&cube) {
+            232 + gray_index as u8
+        } else {
+            16 + (36 * r + 6 * g + b) as u8
+        };
+    }
+
+    /// Closest color in the 8 color terminal palette
+    ///
+    /// Colors are numbered the same way as curses colors (black, red, green, yellow, blue,
+    /// magenta, cyan, white)
+    pub fn to_ansi8(&self) -> u8 {
+        let color = { *self }.normalized();
+        let mut index = 0;
+        if color.r >= 128 {
+            index |= 1;
+        }
+        if color.g >= 128 {
+            index |= 2;
+        }
+        if color.b >= 128 {
+            index |= 4;
+        }
+        return index;
+    }
+
+    /// Squared distance between two colors' RGB channels
+    fn distance_squared(&self, other: &Color32) -> i64 {
+        let dr = self.r as i64 - other.r as i64;
+        let dg = self.g as i64 - other.g as i64;
+        let db = self.b as i64 - other.b as i64;
+        return dr * dr + dg * dg + db * db;
+    }
 }
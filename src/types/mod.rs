@@ -1,2 +1,5 @@
+pub mod color;
+pub mod color32;
+pub mod rect_int;
 pub mod vector2;
 pub mod vector2int;
@@ -0,0 +1,77 @@
+use crate::types::vector2int::Vector2Int;
+use std::fmt;
+
+/// A type that holds an integer rectangle (top left position and size)
+pub struct RectInt {
+    pub position: Vector2Int,
+    pub size: Vector2Int,
+}
+
+impl fmt::Display for RectInt {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "({}, {})", self.position, self.size)
+    }
+}
+
+impl Copy for RectInt {}
+
+impl Clone for RectInt {
+    fn clone(&self) -> RectInt {
+        *self
+    }
+}
+
+// Logical operator overloading for type RectInt
+impl PartialEq for RectInt {
+    fn eq(&self, other: &RectInt) -> bool {
+        return self.position == other.position && self.size == other.size;
+    }
+}
+
+impl RectInt {
+    /// Default constructor to initialize RectInt
+    pub fn new(position: Vector2Int, size: Vector2Int) -> Self {
+        return Self { position, size };
+    }
+
+    /// Return left edge x
+    pub fn left(&self) -> i32 {
+        return self.position.x;
+    }
+
+    /// Return right edge x (exclusive)
+    pub fn right(&self) -> i32 {
+        return self.position.x + self.size.x;
+    }
+
+    /// Return top edge y
+    pub fn top(&self) -> i32 {
+        return self.position.y;
+    }
+
+    /// Return bottom edge y (exclusive)
+    pub fn bottom(&self) -> i32 {
+        return self.position.y + self.size.y;
+    }
+
+    /// Return center point of rectangle
+    pub fn center(&self) -> Vector2Int {
+        return self.position + self.size / 2;
+    }
+
+    /// Check if a point is inside rectangle
+    pub fn contains(&self, point: Vector2Int) -> bool {
+        return point.x >= self.left()
+            && point.x < self.right()
+            && point.y >= self.top()
+            && point.y < self.bottom();
+    }
+
+    /// Check if rectangle overlaps another rectangle
+    pub fn intersects(&self, other: RectInt) -> bool {
+        return self.left() < other.right()
+            && other.left() < self.right()
+            && self.top() < other.bottom()
+            && other.top() < self.bottom();
+    }
+}
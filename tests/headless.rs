@@ -0,0 +1,271 @@
+// Like the crate, tests favour explicit returns
+#![allow(clippy::needless_return)]
+
+use lunar::{
+    app::App,
+    backend::headless_backend::HeadlessBackend,
+    ecs::schedule::Stage,
+    entities::{char_object::CharObject, game_object::GameObject, text_object::TextObject},
+    game::Game,
+    input::{InputEvent, SpecialKey},
+    rendering::{cell::Cell, style::Style},
+    types::{color32::Color32, vector2int::Vector2Int},
+};
+use ndarray::array;
+use std::{cell::Cell as Counter, rc::Rc};
+
+const SIZE: Vector2Int = Vector2Int { x: 20, y: 8 };
+
+/// Game running a fixed number of frames, recording what it was told
+struct Recorder {
+    frames: u64,
+    screen: HeadlessBackend,
+    events: Vec<InputEvent>,
+    pressed: Vec<char>,
+    resizes: Vec<Vector2Int>,
+    render: fn(&mut App),
+}
+
+impl Recorder {
+    fn new(screen: &HeadlessBackend, frames: u64, render: fn(&mut App)) -> Self {
+        return Recorder {
+            frames,
+            screen: screen.clone(),
+            events: Vec::new(),
+            pressed: Vec::new(),
+            resizes: Vec::new(),
+            render,
+        };
+    }
+}
+
+impl Game for Recorder {
+    fn init(&mut self, app: &mut App) {
+        app.set_size(SIZE);
+        app.set_framerate(1000);
+    }
+
+    fn update(&mut self, app: &mut App) {
+        for char in ['a', 'b'].iter() {
+            if app.input.is_pressed(*char) {
+                self.pressed.push(*char);
+            }
+        }
+        if self.screen.get_frame_count() + 1 >= self.frames {
+            app.quit();
+        }
+    }
+
+    fn render(&mut self, app: &mut App) {
+        (self.render)(app);
+    }
+
+    fn on_event(&mut self, _app: &mut App, event: InputEvent) {
+        self.events.push(event);
+    }
+
+    fn on_resize(&mut self, _app: &mut App, size: Vector2Int) {
+        self.resizes.push(size);
+    }
+}
+
+/// Run a game on a headless backend, whose clone keeps the last frame to inspect
+fn run(game: &mut Recorder, screen: &HeadlessBackend) {
+    App::with_backend(Box::new(screen.clone())).run_game(game);
+}
+
+#[test]
+fn draws_text_in_app_colors() {
+    let screen = HeadlessBackend::new(SIZE);
+    let mut game = Recorder::new(&screen, 1, |app| {
+        app.set_background_color(Color32::black());
+        app.set_foreground_color(Color32::white());
+        app.draw_str(Vector2Int { x: 2, y: 1 }, "hello");
+    });
+    run(&mut game, &screen);
+
+    assert_eq!(screen.get_frame_count(), 1);
+    assert!(screen.get_row(1).starts_with("│ hello"));
+    let cell = screen.get_cell(Vector2Int { x: 2, y: 1 }).unwrap();
+    assert_eq!(cell.glyph, 'h');
+    assert_eq!(cell.foreground, Color32::white());
+    assert_eq!(cell.background, Color32::black());
+}
+
+#[test]
+fn draws_cells_with_colors_and_styles() {
+    let screen = HeadlessBackend::new(SIZE);
+    let mut game = Recorder::new(&screen, 1, |app| {
+        let cell = Cell::new('#')
+            .with_foreground(Color32::red())
+            .with_background(Color32::yellow())
+            .with_style(Style::BOLD | Style::UNDERLINE);
+        app.draw_cell(Vector2Int { x: 4, y: 3 }, cell);
+    });
+    run(&mut game, &screen);
+
+    let cell = screen.get_cell(Vector2Int { x: 4, y: 3 }).unwrap();
+    assert_eq!(cell.glyph, '#');
+    assert_eq!(cell.foreground, Color32::red());
+    assert_eq!(cell.background, Color32::yellow());
+    assert!(cell.style.contains(Style::BOLD));
+    assert!(cell.style.contains(Style::UNDERLINE));
+    assert!(!cell.style.contains(Style::ITALIC));
+}
+
+#[test]
+fn renders_world_entities_with_object_colors() {
+    let screen = HeadlessBackend::new(SIZE);
+    let mut game = Recorder::new(&screen, 1, |app| {
+        if !app.world.is_empty() {
+            return;
+        }
+        let map = app.assets.add(
+            lunar::assets::char_map_loader::CharMapAsset::new(array![['<', '>']], None, None)
+                .unwrap(),
+        );
+        let sprite = CharObject::from_asset(&app.assets, map)
+            .unwrap()
+            .set_foreground_color(Color32::red());
+        let entity = app.world.spawn();
+        app.world
+            .add_component(entity, GameObject::new().move_to(Vector2Int { x: 5, y: 2 }));
+        app.world.add_component(entity, sprite);
+
+        let text = app.assets.add("score".to_string());
+        let label = TextObject::from_asset(&app.assets, text)
+            .unwrap()
+            .set_style(Style::REVERSE);
+        let entity = app.world.spawn();
+        app.world
+            .add_component(entity, GameObject::new().move_to(Vector2Int { x: 1, y: 6 }));
+        app.world.add_component(entity, label);
+    });
+    run(&mut game, &screen);
+
+    assert_eq!(screen.get_char(Vector2Int { x: 5, y: 2 }), Some('<'));
+    assert_eq!(screen.get_char(Vector2Int { x: 6, y: 2 }), Some('>'));
+    let cell = screen.get_cell(Vector2Int { x: 6, y: 2 }).unwrap();
+    assert_eq!(cell.foreground, Color32::red());
+    assert!(screen.get_row(6).starts_with("│score"));
+    let cell = screen.get_cell(Vector2Int { x: 1, y: 6 }).unwrap();
+    assert!(cell.style.contains(Style::REVERSE));
+}
+
+#[test]
+fn reads_pushed_input_in_order() {
+    let screen = HeadlessBackend::new(SIZE);
+    screen.push_str("ab");
+    screen.push_input(InputEvent::Special(SpecialKey::Up));
+    let mut game = Recorder::new(&screen, 2, |_app| {});
+    run(&mut game, &screen);
+
+    assert!(
+        game.events
+            == vec![
+                InputEvent::Character('a'),
+                InputEvent::Character('b'),
+                InputEvent::Special(SpecialKey::Up),
+            ]
+    );
+    assert_eq!(game.pressed, vec!['a', 'b']);
+}
+
+#[test]
+fn follows_resize_events() {
+    let screen = HeadlessBackend::new(SIZE);
+    let size = Vector2Int { x: 30, y: 10 };
+    screen.push_input(InputEvent::Resize(size));
+    let mut game = Recorder::new(&screen, 2, |app| {
+        let corner = app.get_size() - Vector2Int { x: 1, y: 1 };
+        app.draw_char(corner, '+');
+    });
+    run(&mut game, &screen);
+
+    assert!(game.resizes == vec![size]);
+    assert_eq!(screen.get_row(0).chars().count(), 30);
+    assert_eq!(screen.get_char(Vector2Int { x: 29, y: 9 }), Some('+'));
+}
+
+#[test]
+fn presents_only_changed_cells() {
+    let screen = HeadlessBackend::new(SIZE);
+    let mut game = Recorder::new(&screen, 3, |app| {
+        app.draw_char(Vector2Int { x: 0, y: 0 }, '@');
+    });
+    run(&mut game, &screen);
+
+    assert_eq!(screen.get_frame_count(), 3);
+    assert_eq!(screen.get_cells_written(), 0);
+    assert_eq!(screen.get_char(Vector2Int { x: 0, y: 0 }), Some('@'));
+}
+
+#[test]
+fn system_clearing_itself_stops_running() {
+    let screen = HeadlessBackend::new(SIZE);
+    let runs = Rc::new(Counter::new(0));
+    let (system_runs, frames) = (runs.clone(), screen.clone());
+    let init = move |app: &mut App| {
+        app.set_framerate(1000);
+        let runs = system_runs.clone();
+        app.add_system(Stage::Update, move |app: &mut App| {
+            runs.set(runs.get() + 1);
+            app.clear_systems();
+        });
+    };
+    let update = move |app: &mut App| {
+        if frames.get_frame_count() + 1 >= 3 {
+            app.quit();
+        }
+    };
+    App::with_backend(Box::new(screen.clone())).run(init, update, |_| {}, |_| {});
+
+    assert_eq!(screen.get_frame_count(), 3);
+    assert_eq!(runs.get(), 1);
+}
+
+#[test]
+fn places_sprites_relative_to_parents() {
+    let screen = HeadlessBackend::new(SIZE);
+    let mut game = Recorder::new(&screen, 1, |app| {
+        if !app.world.is_empty() {
+            return;
+        }
+        let map = app.assets.add(
+            lunar::assets::char_map_loader::CharMapAsset::new(array![['#']], None, None).unwrap(),
+        );
+        let parent = app.world.spawn();
+        app.world
+            .add_component(parent, GameObject::new().move_to(Vector2Int { x: 5, y: 3 }));
+
+        // A sprite's own parent places it without a GameObject component
+        let child = app.world.spawn();
+        let sprite = CharObject::from_asset(&app.assets, map)
+            .unwrap()
+            .set_parent(parent)
+            .move_to(Vector2Int { x: 1, y: 0 });
+        app.world.add_component(child, sprite);
+
+        // With one, the sprite is offset from it and hides on its own
+        let offset = app.world.spawn();
+        app.world
+            .add_component(offset, GameObject::new().set_parent(parent));
+        let sprite = CharObject::from_asset(&app.assets, map)
+            .unwrap()
+            .move_to(Vector2Int { x: 0, y: 2 });
+        app.world.add_component(offset, sprite);
+        let hidden = app.world.spawn();
+        app.world
+            .add_component(hidden, GameObject::new().set_parent(parent));
+        let sprite = CharObject::from_asset(&app.assets, map)
+            .unwrap()
+            .set_visible(false);
+        app.world.add_component(hidden, sprite);
+    });
+    run(&mut game, &screen);
+
+    assert_eq!(screen.get_char(Vector2Int { x: 6, y: 3 }), Some('#'));
+    assert_eq!(screen.get_char(Vector2Int { x: 5, y: 5 }), Some('#'));
+    assert_eq!(screen.get_char(Vector2Int { x: 5, y: 3 }), Some(' '));
+    assert_eq!(screen.get_char(Vector2Int { x: 1, y: 0 }), Some('─'));
+}